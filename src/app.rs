@@ -1,7 +1,12 @@
-use std::{collections::HashMap, fs::File, io::Read};
+use std::{
+    cell::{RefCell, RefMut},
+    collections::{BTreeSet, HashMap},
+    fs::File,
+    io::Read,
+    rc::Rc,
+};
 
-use cached::proc_macro::cached;
-use chrono::{Datelike, NaiveDate, Utc};
+use chrono::{Datelike, Months, NaiveDate, Utc};
 use directories::ProjectDirs;
 use eframe::{
     egui::{
@@ -17,24 +22,91 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::{
-    FixedExpense, NewExpenseWindow, NewIncomeWindow, NewPunctualIncomeWindow,
-    NewSubscriptionWindow, Subscription,
+    allocate_contributions, allocate_savings, clamp_to_month, default_categories, months_between,
+    scenario_to_lang_str, settle, to_real,
+    utils::{APPLICATION, ORGANIZATION, QUALIFIER},
+    windows::interest_editor,
+    Account, Budget, BudgetStatus, CategoriesWindow, DebtPlannerWindow, FixedExpense, FlowKind,
+    Forecast, ForecastEntry, Goal, HolidayCalendar, Installment, InterestRate, LabelFilter, Labels,
+    Loan, LoanScheduleWindow, Member, Money, NewAccountWindow, NewBudgetWindow, NewExpenseWindow,
+    NewGoalWindow, NewIncomeWindow, NewInstallmentWindow, NewLoanWindow, NewMemberWindow,
+    NewPriceChangeWindow, NewPunctualIncomeWindow, NewSubscriptionWindow, NewTransactionWindow,
+    NewTransferWindow, OccurrencesWindow, Scenario, Settlement, Split, SplitWindow, Subscription,
+    SubscriptionDetailsWindow, TaxProfiles, Transaction, Transfer,
 };
 
-const QUALIFIER: &str = "com";
-const ORGANIZATION: &str = "margual56";
-const APPLICATION: &str = "NixBucks";
+/// How many years the long-range projection covers, counting the current one.
+const PROJECTION_YEARS: i32 = 10;
+
+/// The color of the budgets that are about to be exceeded.
+const AMBER: Color32 = Color32::from_rgb(255, 191, 0);
+
+/// The forecasts every report is computed from. They are built the first time they're needed and
+/// dropped whenever the data changes, so they aren't built again on every frame.
+#[derive(Clone, Default)]
+struct Forecasts {
+    /// The day they were built on, since the forecasts start on that day.
+    built_on: Option<NaiveDate>,
+    /// From the start of the year until yesterday, to compare the plan with the ledger.
+    past: Option<Rc<Forecast>>,
+    /// From today until the horizon, for each scenario.
+    future: HashMap<Scenario, Rc<Forecast>>,
+    /// The stats filter they were made for, the forecast of the stats until the end of the year
+    /// and the runway.
+    stats: Option<(LabelFilter, Rc<Forecast>, Option<u32>)>,
+}
 
 #[derive(Serialize, Deserialize, Clone)]
 pub struct App {
-    initial_savings: f32,
+    initial_savings: Money,
+    #[serde(default)]
+    savings_interest: InterestRate,
+    /// The annual inflation rate in basis points (hundredths of a percent).
+    #[serde(default)]
+    inflation: u32,
     subscriptions: HashMap<Uuid, Subscription>,
     incomes: HashMap<Uuid, Subscription>,
     fixed_expenses: HashMap<Uuid, FixedExpense>,
     p_incomes: HashMap<Uuid, FixedExpense>,
+    #[serde(default)]
+    installments: HashMap<Uuid, Installment>,
+    #[serde(default)]
+    loans: HashMap<Uuid, Loan>,
+    #[serde(default)]
+    accounts: HashMap<Uuid, Account>,
+    #[serde(default)]
+    transfers: HashMap<Uuid, Transfer>,
+    #[serde(default)]
+    transactions: HashMap<Uuid, Transaction>,
+    #[serde(default)]
+    budgets: HashMap<Uuid, Budget>,
+    #[serde(default)]
+    goals: HashMap<Uuid, Goal>,
+    #[serde(default)]
+    members: HashMap<Uuid, Member>,
+    #[serde(default = "default_categories")]
+    categories: Vec<String>,
     dismissed_ad: bool,
     lang: String,
 
+    #[serde(skip)]
+    holidays: HolidayCalendar,
+
+    #[serde(skip)]
+    tax_profiles: TaxProfiles,
+
+    #[serde(skip)]
+    stats_filter: LabelFilter,
+
+    #[serde(skip)]
+    forecasts: RefCell<Forecasts>,
+
+    #[serde(skip)]
+    show_variance_per_month: bool,
+
+    #[serde(skip)]
+    subscription_search: String,
+
     #[serde(skip)]
     new_subscription_window: Option<NewSubscriptionWindow>,
 
@@ -46,6 +118,51 @@ pub struct App {
 
     #[serde(skip)]
     new_p_income_window: Option<NewPunctualIncomeWindow>,
+
+    #[serde(skip)]
+    new_installment_window: Option<NewInstallmentWindow>,
+
+    #[serde(skip)]
+    new_loan_window: Option<NewLoanWindow>,
+
+    #[serde(skip)]
+    loan_schedule_window: Option<LoanScheduleWindow>,
+
+    #[serde(skip)]
+    debt_planner_window: Option<DebtPlannerWindow>,
+
+    #[serde(skip)]
+    new_price_change_window: Option<(Uuid, NewPriceChangeWindow)>,
+
+    #[serde(skip)]
+    occurrences_window: Option<(Uuid, OccurrencesWindow)>,
+
+    #[serde(skip)]
+    subscription_details_window: Option<(Uuid, SubscriptionDetailsWindow)>,
+
+    #[serde(skip)]
+    categories_window: Option<CategoriesWindow>,
+
+    #[serde(skip)]
+    new_account_window: Option<NewAccountWindow>,
+
+    #[serde(skip)]
+    new_transfer_window: Option<NewTransferWindow>,
+
+    #[serde(skip)]
+    new_transaction_window: Option<NewTransactionWindow>,
+
+    #[serde(skip)]
+    new_budget_window: Option<NewBudgetWindow>,
+
+    #[serde(skip)]
+    new_goal_window: Option<NewGoalWindow>,
+
+    #[serde(skip)]
+    new_member_window: Option<NewMemberWindow>,
+
+    #[serde(skip)]
+    split_window: Option<(Uuid, SplitWindow)>,
 }
 
 impl Default for App {
@@ -56,18 +173,50 @@ impl Default for App {
                 Err(e) => {
                     println!("Error while opening file: {}", e);
                     return Self {
-                        initial_savings: 0.0,
+                        initial_savings: Money::ZERO,
+                        savings_interest: InterestRate::default(),
+                        inflation: 0,
                         subscriptions: HashMap::new(),
                         fixed_expenses: HashMap::new(),
                         incomes: HashMap::new(),
                         p_incomes: HashMap::new(),
+                        installments: HashMap::new(),
+                        loans: HashMap::new(),
+                        accounts: HashMap::new(),
+                        transfers: HashMap::new(),
+                        transactions: HashMap::new(),
+                        budgets: HashMap::new(),
+                        goals: HashMap::new(),
+                        members: HashMap::new(),
+                        categories: default_categories(),
                         dismissed_ad: false,
                         lang: String::from("en"),
+                        holidays: HolidayCalendar::load(),
+                        tax_profiles: TaxProfiles::load(),
+                        stats_filter: LabelFilter::default(),
+                        forecasts: RefCell::default(),
+                        show_variance_per_month: false,
+                        subscription_search: String::new(),
 
                         new_subscription_window: None,
                         new_expense_window: None,
                         new_income_window: None,
                         new_p_income_window: None,
+                        new_installment_window: None,
+                        new_loan_window: None,
+                        loan_schedule_window: None,
+                        debt_planner_window: None,
+                        new_price_change_window: None,
+                        occurrences_window: None,
+                        subscription_details_window: None,
+                        categories_window: None,
+                        new_account_window: None,
+                        new_transfer_window: None,
+                        new_transaction_window: None,
+                        new_budget_window: None,
+                        new_goal_window: None,
+                        new_member_window: None,
+                        split_window: None,
                     };
                 }
             };
@@ -76,43 +225,73 @@ impl Default for App {
 
             path.read_to_string(&mut buffer).unwrap();
 
-            serde_json::from_str::<Self>(&buffer).unwrap().update()
+            let mut app = serde_json::from_str::<Self>(&buffer).unwrap();
+            app.holidays = HolidayCalendar::load();
+            app.tax_profiles = TaxProfiles::load();
+            app.apply_tax_profiles();
+
+            app.update()
         } else {
             println!("Directory not found, returning default value");
             Self {
-                initial_savings: 0.0,
+                initial_savings: Money::ZERO,
+                savings_interest: InterestRate::default(),
+                inflation: 0,
                 subscriptions: HashMap::new(),
                 fixed_expenses: HashMap::new(),
                 incomes: HashMap::new(),
                 p_incomes: HashMap::new(),
+                installments: HashMap::new(),
+                loans: HashMap::new(),
+                accounts: HashMap::new(),
+                transfers: HashMap::new(),
+                transactions: HashMap::new(),
+                budgets: HashMap::new(),
+                goals: HashMap::new(),
+                members: HashMap::new(),
+                categories: default_categories(),
                 dismissed_ad: false,
                 lang: String::from("en"),
+                holidays: HolidayCalendar::load(),
+                tax_profiles: TaxProfiles::load(),
+                stats_filter: LabelFilter::default(),
+                forecasts: RefCell::default(),
+                show_variance_per_month: false,
+                subscription_search: String::new(),
 
                 new_subscription_window: None,
                 new_expense_window: None,
                 new_income_window: None,
                 new_p_income_window: None,
+                new_installment_window: None,
+                new_loan_window: None,
+                loan_schedule_window: None,
+                debt_planner_window: None,
+                new_price_change_window: None,
+                occurrences_window: None,
+                subscription_details_window: None,
+                categories_window: None,
+                new_account_window: None,
+                new_transfer_window: None,
+                new_transaction_window: None,
+                new_budget_window: None,
+                new_goal_window: None,
+                new_member_window: None,
+                split_window: None,
             }
         }
     }
 }
 
-#[cached]
-fn cost_to_year_end(subscriptions: Vec<Subscription>, expenses: Vec<FixedExpense>) -> f32 {
-    let mut amount = 0.0;
-    let year_end = NaiveDate::from_ymd_opt(Utc::now().year(), 12, 31).unwrap();
-
-    for subscription in subscriptions {
-        amount += subscription.cost_until(year_end);
-    }
+/// Draws the category of an entry, with its tags on hover.
+fn labels_label(ui: &mut egui::Ui, labels: &Labels) {
+    let label = ui.label(RichText::new(
+        labels.category.clone().unwrap_or_else(|| String::from("-")),
+    ));
 
-    for expense in expenses {
-        if Utc::now().naive_utc().date() <= expense.date() && expense.date() <= year_end {
-            amount += expense.cost();
-        }
+    if !labels.tags.is_empty() {
+        label.on_hover_text(labels.tags_to_string());
     }
-
-    amount
 }
 
 impl App {
@@ -149,7 +328,10 @@ impl App {
     /// - APPLICATION: "NixBucks"
     ///
     /// And appends "config.json" to the path. Then, it overwrites the file with the serialized data.
+    /// The forecasts are dropped, since the data they were built from has changed.
     fn save_data(&self) {
+        *self.forecasts.borrow_mut() = Forecasts::default();
+
         if let Some(dir) = ProjectDirs::from(QUALIFIER, ORGANIZATION, APPLICATION) {
             if !dir.config_dir().exists() {
                 std::fs::create_dir_all(dir.config_dir()).unwrap();
@@ -161,7 +343,9 @@ impl App {
         }
     }
 
-    /// Updates the app by removing the expired subscriptions and incomes and adding the amounts to the "initial amount".
+    /// Updates the app by removing the expired fixed expenses and punctual incomes and adding the
+    /// user's share of their amounts to the balance of their accounts. The actual amounts of the
+    /// ledger are used when there are transactions linked to them.
     fn update(&self) -> Self {
         let mut app = self.clone();
 
@@ -169,14 +353,14 @@ impl App {
 
         for (uuid, expense) in self.fixed_expenses.clone() {
             if today > expense.date {
-                app.initial_savings -= expense.cost();
+                app.settle(uuid, -expense.my_cost(), expense.account());
                 app.remove_expense(uuid);
             }
         }
 
         for (uuid, income) in self.p_incomes.clone() {
             if today > income.date {
-                app.initial_savings += income.cost();
+                app.settle(uuid, income.my_cost(), income.account());
                 app.remove_punctual_income(&uuid);
             }
         }
@@ -186,6 +370,18 @@ impl App {
         app.clone()
     }
 
+    /// Sets the tax profile of every income entered as gross, so that their net amounts are used.
+    fn apply_tax_profiles(&mut self) {
+        for income in self.incomes.values_mut() {
+            let tax = income
+                .tax_profile()
+                .and_then(|name| self.tax_profiles.get(name))
+                .cloned();
+
+            income.set_tax(tax);
+        }
+    }
+
     /// Removes an expense.
     /// # Arguments
     /// - `uuid`: The UUID of the expense to remove.
@@ -200,44 +396,760 @@ impl App {
         self.p_incomes.remove(uuid);
     }
 
-    /// Returns the total cost of all subscriptions in a whole year.
-    #[allow(dead_code)]
-    fn yearly_costs(&self) -> f32 {
-        let mut amount = 0.0;
+    /// Returns the accounts besides the main one, sorted by name.
+    fn accounts_list(&self) -> Vec<Account> {
+        let mut accounts: Vec<Account> = self.accounts.values().cloned().collect();
+        accounts.sort_by(|a, b| a.name().cmp(b.name()));
 
-        for subscription in self.subscriptions.values() {
-            amount += subscription.cost_per_year();
+        accounts
+    }
+
+    /// Returns the account an entry is charged to. The entries whose account was deleted are
+    /// charged to the main account (`None`).
+    fn resolve_account(&self, account: Option<Uuid>) -> Option<Uuid> {
+        account.filter(|uuid| self.accounts.contains_key(uuid))
+    }
+
+    /// Returns the name of the given account.
+    fn account_name(&self, account: Option<Uuid>) -> String {
+        match self.resolve_account(account) {
+            Some(uuid) => self.accounts[&uuid].name().to_string(),
+            None => t!("window.common.main_account", self.lang),
         }
+    }
 
-        amount
+    /// Returns the current balance of the given account.
+    fn account_balance(&self, account: Option<Uuid>) -> Money {
+        match self.resolve_account(account) {
+            Some(uuid) => self.accounts[&uuid].balance(),
+            None => self.initial_savings,
+        }
     }
 
-    /// Returns the total cost of all subscriptions in a month.
-    fn monthly_costs(&self) -> f32 {
-        let mut amount = 0.0;
+    /// Returns the interest rate of the given account.
+    fn account_interest(&self, account: Option<Uuid>) -> InterestRate {
+        match self.resolve_account(account) {
+            Some(uuid) => self.accounts[&uuid].interest(),
+            None => self.savings_interest,
+        }
+    }
 
-        for subscription in self.subscriptions.values() {
-            amount += subscription.cost_per_month();
+    /// Returns the interest the current balance of the given account earns from today until the
+    /// given date, as paid in the forecast.
+    fn interest_until(&self, account: Option<Uuid>, to: NaiveDate) -> Money {
+        let account = self.resolve_account(account);
+        let forecast = self.forecast(Scenario::Expected);
+
+        forecast.total_between(forecast.from(), to, |change| {
+            change.kind == FlowKind::Interest && self.resolve_account(change.account) == account
+        })
+    }
+
+    /// Returns the interest the money saved for a goal earns until its date: the part of the
+    /// interest of its account that matches the part of the balance allocated to the goal.
+    fn goal_interest(&self, goal: &Goal, saved: Money) -> Money {
+        let balance = self.account_balance(goal.account());
+
+        if balance <= Money::ZERO {
+            return Money::ZERO;
         }
 
-        amount
+        self.interest_until(goal.account(), goal.date())
+            .mul_ratio(saved.cents(), balance.cents())
+    }
+
+    /// Returns a mutable reference to the current balance of the given account.
+    fn account_balance_mut(&mut self, account: Option<Uuid>) -> &mut Money {
+        match self.resolve_account(account) {
+            Some(uuid) => &mut self.accounts.get_mut(&uuid).unwrap().balance,
+            None => &mut self.initial_savings,
+        }
+    }
+
+    /// Removes an account. Its entries are moved to the main account, and the transfers from or to
+    /// it are removed.
+    /// # Arguments
+    /// - `uuid`: The UUID of the account to remove.
+    fn remove_account(&mut self, uuid: Uuid) {
+        self.accounts.remove(&uuid);
+
+        for subscription in self
+            .subscriptions
+            .values_mut()
+            .chain(self.incomes.values_mut())
+            .filter(|s| s.account() == Some(uuid))
+        {
+            subscription.set_account(None);
+        }
+
+        for expense in self
+            .fixed_expenses
+            .values_mut()
+            .chain(self.p_incomes.values_mut())
+            .filter(|e| e.account() == Some(uuid))
+        {
+            expense.account = None;
+        }
+
+        for installment in self
+            .installments
+            .values_mut()
+            .filter(|i| i.account() == Some(uuid))
+        {
+            installment.set_account(None);
+        }
+
+        for loan in self
+            .loans
+            .values_mut()
+            .filter(|l| l.account() == Some(uuid))
+        {
+            loan.set_account(None);
+        }
+
+        for goal in self
+            .goals
+            .values_mut()
+            .filter(|g| g.account() == Some(uuid))
+        {
+            goal.account = None;
+        }
+
+        self.transfers
+            .retain(|_, t| t.from() != Some(uuid) && t.to() != Some(uuid));
+    }
+
+    /// Returns the current balance of all the accounts together.
+    fn total_balance(&self) -> Money {
+        self.initial_savings + self.accounts.values().map(|a| a.balance()).sum()
+    }
+
+    /// Returns the last day of the forecasts: the end of the long-range projection, or the date of
+    /// the latest goal if it's later.
+    fn horizon(&self) -> NaiveDate {
+        let today = Utc::now().date_naive();
+        let projection = today + Months::new(12 * PROJECTION_YEARS as u32);
+
+        self.goals
+            .values()
+            .map(|goal| goal.date())
+            .fold(projection, NaiveDate::max)
     }
 
-    /// Returns the balance at the end of each month (all income streams - all subscriptions).
-    fn monthly_balance(&self) -> f32 {
-        let mut amount = 0.0;
+    /// Builds the day-by-day forecast of all the accounts together between the two given dates,
+    /// without the interest. The transfers are added to both of their accounts, so they don't
+    /// change the total balance.
+    /// # Arguments
+    /// - `opening`: The balance before the first day.
+    /// - `from`: The first day.
+    /// - `to`: The last day.
+    /// - `scenario`: Which amount of the uncertain entries to use.
+    fn build_forecast(
+        &self,
+        opening: Money,
+        from: NaiveDate,
+        to: NaiveDate,
+        scenario: Scenario,
+    ) -> Forecast {
+        let mut forecast = Forecast::new(opening, from, to, self.inflation);
 
         for income in self.incomes.values() {
-            amount += income.cost_per_month();
+            forecast.add_subscription(income, FlowKind::Income, &self.holidays, scenario.income());
+        }
+
+        for income in self.p_incomes.values() {
+            forecast.add_fixed(income, FlowKind::Income, scenario.income());
         }
 
         for subscription in self.subscriptions.values() {
-            amount -= subscription.cost();
+            forecast.add_subscription(
+                subscription,
+                FlowKind::Cost,
+                &self.holidays,
+                scenario.cost(),
+            );
+        }
+
+        for expense in self.fixed_expenses.values() {
+            forecast.add_fixed(expense, FlowKind::Cost, scenario.cost());
+        }
+
+        for installment in self.installments.values() {
+            forecast.add_installment(installment);
+        }
+
+        for loan in self.loans.values() {
+            forecast.add_loan(loan);
+        }
+
+        for transfer in self.transfers.values() {
+            forecast.add_transfer(transfer, transfer.from());
+            forecast.add_transfer(transfer, transfer.to());
+        }
+
+        forecast
+    }
+
+    /// Returns the forecasts built so far, dropping them if they were built on another day.
+    fn forecasts(&self) -> RefMut<'_, Forecasts> {
+        let today = Utc::now().date_naive();
+        let mut forecasts = self.forecasts.borrow_mut();
+
+        if forecasts.built_on != Some(today) {
+            *forecasts = Forecasts {
+                built_on: Some(today),
+                ..Forecasts::default()
+            };
+        }
+
+        forecasts
+    }
+
+    /// Returns the day-by-day forecast of all the accounts together, from today until the
+    /// horizon, with the interest of every account. Every report on the future is computed from
+    /// it, so they all agree with each other.
+    /// # Arguments
+    /// - `scenario`: Which amount of the uncertain entries to use.
+    fn forecast(&self, scenario: Scenario) -> Rc<Forecast> {
+        self.forecasts()
+            .future
+            .entry(scenario)
+            .or_insert_with(|| {
+                let today = Utc::now().date_naive();
+                let mut forecast =
+                    self.build_forecast(self.total_balance(), today, self.horizon(), scenario);
+
+                for account in
+                    std::iter::once(None).chain(self.accounts.keys().map(|uuid| Some(*uuid)))
+                {
+                    forecast.add_interest(
+                        account,
+                        &self.account_name(account),
+                        self.account_balance(account),
+                        self.account_interest(account),
+                    );
+                }
+
+                Rc::new(forecast)
+            })
+            .clone()
+    }
+
+    /// Returns the forecast of all the accounts from the start of the year until yesterday, with
+    /// the expected amounts. Its balance starts at zero, since only its changes are used.
+    fn past_forecast(&self) -> Rc<Forecast> {
+        self.forecasts()
+            .past
+            .get_or_insert_with(|| {
+                let today = Utc::now().date_naive();
+                let year_start = NaiveDate::from_ymd_opt(today.year(), 1, 1).unwrap();
+                let yesterday = today.pred_opt().unwrap_or(today);
+
+                Rc::new(self.build_forecast(Money::ZERO, year_start, yesterday, Scenario::Expected))
+            })
+            .clone()
+    }
+
+    /// Returns the planned changes between the two given dates (both included), with the
+    /// expected amounts: the ones of this year until yesterday and the forecast ones from today on.
+    fn planned_changes(&self, from: NaiveDate, to: NaiveDate) -> Vec<ForecastEntry> {
+        let past = self.past_forecast();
+        let future = self.forecast(Scenario::Expected);
+
+        past.entries()
+            .iter()
+            .chain(future.entries())
+            .filter(|change| from <= change.date && change.date <= to)
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the sum of the planned changes between the two given dates (both included) that
+    /// satisfy the condition, like [`App::planned_changes`]. It's negative for the costs.
+    fn planned_total(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+        condition: impl Fn(&ForecastEntry) -> bool,
+    ) -> Money {
+        self.past_forecast().total_between(from, to, &condition)
+            + self
+                .forecast(Scenario::Expected)
+                .total_between(from, to, &condition)
+    }
+
+    /// Returns true if a change of the forecast counts in the stats: the interest and the
+    /// transfers always do, and the rest only if their labels match the stats filter.
+    fn in_stats(&self, change: &ForecastEntry) -> bool {
+        matches!(change.kind, FlowKind::Interest | FlowKind::Transfer)
+            || self.stats_filter.matches(&change.labels)
+    }
+
+    /// Returns the forecast of the stats, from today until the end of the year, and how many
+    /// whole months the current balance lasts if all the income stops. Only the entries that match
+    /// the stats filter are counted.
+    fn stats_forecast(&self) -> (Rc<Forecast>, Option<u32>) {
+        if let Some((filter, forecast, runway)) = &self.forecasts().stats {
+            if *filter == self.stats_filter {
+                return (forecast.clone(), *runway);
+            }
+        }
+
+        let year_end = NaiveDate::from_ymd_opt(Utc::now().year(), 12, 31).unwrap();
+        let forecast = self.forecast(Scenario::Expected);
+        let filtered = forecast.filtered(self.total_balance(), forecast.to(), |change| {
+            self.in_stats(change)
+        });
+        let stats = Rc::new(filtered.filtered(filtered.opening(), year_end, |_| true));
+        let runway = filtered.runway();
+
+        self.forecasts().stats = Some((self.stats_filter.clone(), stats.clone(), runway));
+
+        (stats, runway)
+    }
+
+    /// Returns the projected balance of the given account at the end of the year: its interest,
+    /// its income, its costs and the money transferred into or out of it.
+    fn account_balance_eoy(&self, account: Option<Uuid>) -> Money {
+        let account = self.resolve_account(account);
+        let year_end = NaiveDate::from_ymd_opt(Utc::now().year(), 12, 31).unwrap();
+        let forecast = self.forecast(Scenario::Expected);
+
+        self.account_balance(account)
+            + forecast.total_between(forecast.from(), year_end, |change| {
+                self.resolve_account(change.account) == account
+            })
+    }
+
+    /// Returns the household members, sorted by name.
+    fn members_list(&self) -> Vec<Member> {
+        let mut members: Vec<Member> = self.members.values().cloned().collect();
+        members.sort_by(|a, b| a.name().cmp(b.name()));
+
+        members
+    }
+
+    /// Returns the name of a person of the household (`None` is the user).
+    fn member_name(&self, member: Option<Uuid>) -> String {
+        match member.and_then(|uuid| self.members.get(&uuid)) {
+            Some(member) => member.name().to_string(),
+            None => t!("app.household.me", self.lang),
+        }
+    }
+
+    /// Removes a household member and their shares of the expenses.
+    fn remove_member(&mut self, uuid: Uuid) {
+        self.members.remove(&uuid);
+
+        for subscription in self.subscriptions.values_mut() {
+            let mut split = subscription.split().clone();
+            split.remove_member(uuid);
+            subscription.set_split(split);
+        }
+
+        for expense in self.fixed_expenses.values_mut() {
+            expense.split.remove_member(uuid);
+        }
+    }
+
+    /// Returns the shared expenses charged between the two given dates, as (split, cost), with
+    /// their expected amounts.
+    /// # Arguments
+    /// - `from`: The first day.
+    /// - `to`: The last day.
+    fn shared_charges(&self, from: NaiveDate, to: NaiveDate) -> Vec<(Split, Money)> {
+        self.planned_changes(from, to)
+            .into_iter()
+            .filter(|change| change.kind == FlowKind::Cost)
+            .filter_map(|change| {
+                let uuid = change.entry?;
+                let split = self
+                    .subscriptions
+                    .get(&uuid)
+                    .map(|s| s.split())
+                    .or_else(|| self.fixed_expenses.get(&uuid).map(|e| &e.split))?;
+
+                split
+                    .involves_members()
+                    .then(|| (split.clone(), change.members_share - change.amount))
+            })
+            .collect()
+    }
+
+    /// Returns true if any expense is shared with or paid by the household members.
+    fn has_shared_entries(&self) -> bool {
+        self.subscriptions
+            .values()
+            .any(|s| s.split().involves_members())
+            || self
+                .fixed_expenses
+                .values()
+                .any(|e| e.split.involves_members())
+    }
+
+    /// Returns the payments that settle the shared expenses of the current month.
+    fn settlements(&self) -> Vec<Settlement> {
+        let today = Utc::now().date_naive();
+        let from = NaiveDate::from_ymd_opt(today.year(), today.month(), 1).unwrap();
+        let to = clamp_to_month(today.year(), today.month(), 31);
+
+        settle(
+            self.shared_charges(from, to)
+                .into_iter()
+                .flat_map(|(split, cost)| split.debts(cost)),
+        )
+    }
+
+    /// Returns the projected balance of every account at the end of the year, counting only the
+    /// entries that match the stats filter.
+    /// # Arguments
+    /// - `scenario`: Which amount of the uncertain entries to use.
+    fn balance_eoy(&self, scenario: Scenario) -> Money {
+        let year_end = NaiveDate::from_ymd_opt(Utc::now().year(), 12, 31).unwrap();
+        let forecast = self.forecast(scenario);
+
+        self.total_balance()
+            + forecast.total_between(forecast.from(), year_end, |change| self.in_stats(change))
+    }
+
+    /// Returns true if any entry has an uncertain amount.
+    fn has_uncertain_entries(&self) -> bool {
+        self.subscriptions
+            .values()
+            .chain(self.incomes.values())
+            .any(|s| s.uncertainty().is_some())
+            || self
+                .fixed_expenses
+                .values()
+                .chain(self.p_incomes.values())
+                .any(|e| e.uncertainty.is_some())
+    }
+
+    /// Adds a past one-off entry to the balance of its account. If there are transactions linked
+    /// to it, their actual amounts are used instead of the planned one.
+    /// # Arguments
+    /// - `entry`: The UUID of the entry.
+    /// - `planned`: The planned amount, positive for income and negative for costs.
+    /// - `account`: The account of the entry.
+    fn settle(&mut self, entry: Uuid, planned: Money, account: Option<Uuid>) {
+        let actual: Vec<Transaction> = self
+            .transactions
+            .values()
+            .filter(|t| t.entry() == Some(entry))
+            .cloned()
+            .collect();
+
+        if actual.is_empty() {
+            *self.account_balance_mut(account) += planned;
+        } else {
+            for transaction in actual {
+                *self.account_balance_mut(transaction.account()) += transaction.amount();
+            }
+        }
+    }
+
+    /// Returns the uuids and the names of all the planned entries, sorted by name.
+    fn planned_entries(&self) -> Vec<(Uuid, String)> {
+        let mut entries: Vec<(Uuid, String)> = self
+            .subscriptions
+            .iter()
+            .chain(self.incomes.iter())
+            .map(|(uuid, s)| (*uuid, s.name().to_string()))
+            .chain(
+                self.fixed_expenses
+                    .iter()
+                    .chain(self.p_incomes.iter())
+                    .map(|(uuid, e)| (*uuid, e.name().to_string())),
+            )
+            .chain(
+                self.installments
+                    .iter()
+                    .map(|(uuid, i)| (*uuid, i.name().to_string())),
+            )
+            .chain(
+                self.loans
+                    .iter()
+                    .map(|(uuid, l)| (*uuid, l.name().to_string())),
+            )
+            .collect();
+        entries.sort_by(|a, b| a.1.cmp(&b.1));
+
+        entries
+    }
+
+    /// Returns the name of a planned entry, if it still exists.
+    fn entry_name(&self, entry: Uuid) -> Option<String> {
+        self.planned_entries()
+            .into_iter()
+            .find(|(uuid, _)| *uuid == entry)
+            .map(|(_, name)| name)
+    }
+
+    /// Returns the planned amount of every entry between the two given dates (both included),
+    /// positive for income and negative for costs.
+    fn planned_between(&self, from: NaiveDate, to: NaiveDate) -> HashMap<Uuid, Money> {
+        let mut planned = HashMap::new();
+
+        for change in self.planned_changes(from, to) {
+            if let (Some(uuid), FlowKind::Income | FlowKind::Cost) = (change.entry, change.kind) {
+                *planned.entry(uuid).or_insert(Money::ZERO) += change.amount;
+            }
+        }
+
+        planned
+    }
+
+    /// Returns the sum of the transactions between the two given dates (both included) that
+    /// satisfy the given condition.
+    fn actual_between(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+        condition: impl Fn(&Transaction) -> bool,
+    ) -> Money {
+        self.transactions
+            .values()
+            .filter(|t| from <= t.date() && t.date() <= to && condition(t))
+            .map(|t| t.amount())
+            .sum()
+    }
+
+    /// Returns the planned and the actual amounts of each entry from the start of the year until
+    /// today, sorted by name. Only the entries with a planned or an actual amount are returned.
+    fn variance_per_entry(&self) -> Vec<(String, Money, Money)> {
+        let today = Utc::now().date_naive();
+        let year_start = NaiveDate::from_ymd_opt(today.year(), 1, 1).unwrap();
+        let planned = self.planned_between(year_start, today);
+
+        self.planned_entries()
+            .into_iter()
+            .map(|(uuid, name)| {
+                (
+                    name,
+                    planned.get(&uuid).copied().unwrap_or_default(),
+                    self.actual_between(year_start, today, |t| t.entry() == Some(uuid)),
+                )
+            })
+            .filter(|(_, planned, actual)| *planned != Money::ZERO || *actual != Money::ZERO)
+            .collect()
+    }
+
+    /// Returns the planned and the actual balance of each month of the year until today.
+    /// Transactions linked to entries that are not in the plan anymore (e.g. past fixed expenses)
+    /// are left out, since their planned amount is not known.
+    fn variance_per_month(&self) -> Vec<(NaiveDate, Money, Money)> {
+        let today = Utc::now().date_naive();
+
+        (1..=today.month())
+            .map(|month| {
+                let from = NaiveDate::from_ymd_opt(today.year(), month, 1).unwrap();
+                let to = clamp_to_month(today.year(), month, 31).min(today);
+                let planned: Money = self.planned_between(from, to).into_values().sum();
+                let actual = self.actual_between(from, to, |t| {
+                    t.entry()
+                        .is_none_or(|entry| self.entry_name(entry).is_some())
+                });
+
+                (from, planned, actual)
+            })
+            .collect()
+    }
+
+    /// Returns the projected balance of all the accounts at the end of each year, from the current
+    /// one on. The costs and the income of the subscriptions grow with inflation, while the
+    /// installment plans and the loans keep their amounts.
+    /// # Returns
+    /// - The year, the nominal balance and the real balance (in today's money).
+    fn projection(&self) -> Vec<(i32, Money, Money)> {
+        let today = Utc::now().date_naive();
+        let year_ends: Vec<NaiveDate> = (0..PROJECTION_YEARS)
+            .map(|i| NaiveDate::from_ymd_opt(today.year() + i, 12, 31).unwrap())
+            .collect();
+        let forecast = self.forecast(Scenario::Expected);
+
+        year_ends
+            .into_iter()
+            .map(|year_end| {
+                let nominal = forecast.balance_on(year_end);
+
+                (
+                    year_end.year(),
+                    nominal,
+                    to_real(nominal, self.inflation, today, year_end),
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the budgets, sorted by category.
+    fn budgets_list(&self) -> Vec<Budget> {
+        let mut budgets: Vec<Budget> = self.budgets.values().cloned().collect();
+        budgets.sort_by(|a, b| a.category.cmp(&b.category));
+
+        budgets
+    }
+
+    /// Returns the planned spending of a category between the two given dates (both included):
+    /// the user's share of the charges of the subscriptions, the installment plans, the loans and
+    /// the fixed expenses.
+    /// # Arguments
+    /// - `category`: The category.
+    /// - `from`: The starting date.
+    /// - `to`: The target date.
+    /// # Returns
+    /// - The planned spending, as a positive amount.
+    fn planned_spending(&self, category: &str, from: NaiveDate, to: NaiveDate) -> Money {
+        -self.planned_total(from, to, |change| {
+            change.kind == FlowKind::Cost && change.labels.category.as_deref() == Some(category)
+        })
+    }
+
+    /// Returns the budgets that are about to be exceeded or are exceeded in the current month or
+    /// in any of the next 11, in chronological order.
+    /// # Returns
+    /// - The first day of the month, the budget, the planned spending and the status.
+    fn budget_warnings(&self) -> Vec<(NaiveDate, Budget, Money, BudgetStatus)> {
+        let today = Utc::now().date_naive();
+        let month_start = NaiveDate::from_ymd_opt(today.year(), today.month(), 1).unwrap();
+        let budgets = self.budgets_list();
+
+        (0..12)
+            .filter_map(|i| month_start.checked_add_months(Months::new(i)))
+            .flat_map(|from| {
+                let to = clamp_to_month(from.year(), from.month(), 31);
+
+                budgets
+                    .iter()
+                    .map(move |budget| {
+                        let spent = self.planned_spending(&budget.category, from, to);
+
+                        (from, budget.clone(), spent, budget.status(spent))
+                    })
+                    .filter(|(_, _, _, status)| *status != BudgetStatus::Ok)
+            })
+            .collect()
+    }
+
+    /// Removes the category of the entries whose category is not in the list anymore.
+    fn clear_removed_categories(&mut self) {
+        let categories = self.categories.clone();
+        let clear = |labels: &mut Labels| {
+            if labels
+                .category
+                .as_ref()
+                .is_some_and(|category| !categories.contains(category))
+            {
+                labels.category = None;
+            }
+        };
+
+        for subscription in self
+            .subscriptions
+            .values_mut()
+            .chain(self.incomes.values_mut())
+        {
+            let mut labels = subscription.labels().clone();
+            clear(&mut labels);
+            subscription.set_labels(labels);
+        }
+
+        for expense in self
+            .fixed_expenses
+            .values_mut()
+            .chain(self.p_incomes.values_mut())
+        {
+            clear(&mut expense.labels);
+        }
+
+        for installment in self.installments.values_mut() {
+            let mut labels = installment.labels().clone();
+            clear(&mut labels);
+            installment.set_labels(labels);
+        }
+
+        for loan in self.loans.values_mut() {
+            let mut labels = loan.labels().clone();
+            clear(&mut labels);
+            loan.set_labels(labels);
+        }
+
+        self.budgets
+            .retain(|_, budget| categories.contains(&budget.category));
+    }
+
+    /// Returns every tag used by any entry, sorted alphabetically.
+    fn tags(&self) -> BTreeSet<String> {
+        self.subscriptions
+            .values()
+            .chain(self.incomes.values())
+            .flat_map(|s| s.labels().tags.iter())
+            .chain(
+                self.fixed_expenses
+                    .values()
+                    .chain(self.p_incomes.values())
+                    .flat_map(|e| e.labels().tags.iter()),
+            )
+            .chain(
+                self.installments
+                    .values()
+                    .flat_map(|i| i.labels().tags.iter()),
+            )
+            .chain(self.loans.values().flat_map(|l| l.labels().tags.iter()))
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the total cost of all the active subscriptions in a whole year.
+    #[allow(dead_code)]
+    fn yearly_costs(&self) -> Money {
+        let mut amount = Money::ZERO;
+        let today = Utc::now().date_naive();
+
+        for subscription in self
+            .subscriptions
+            .values()
+            .filter(|s| s.is_active(today) && self.stats_filter.matches(s.labels()))
+        {
+            amount += subscription.cost_per_year();
         }
 
         amount
     }
 
+    /// Returns the first and the last day of the next month, starting today.
+    fn next_month(&self) -> (NaiveDate, NaiveDate) {
+        let today = Utc::now().date_naive();
+        let end = (today + Months::new(1)).pred_opt().unwrap_or(today);
+
+        (today, end)
+    }
+
+    /// Returns the costs of the forecast in the next month that match the stats filter, as a
+    /// positive amount.
+    fn monthly_costs(&self) -> Money {
+        let (from, to) = self.next_month();
+
+        -self
+            .forecast(Scenario::Expected)
+            .total_between(from, to, |change| {
+                change.kind == FlowKind::Cost && self.stats_filter.matches(&change.labels)
+            })
+    }
+
+    /// Returns the balance of the next month in the forecast: its income minus its costs, without
+    /// the interest and the transfers between accounts.
+    /// # Arguments
+    /// - `filter`: Only the entries whose labels match it are counted.
+    fn monthly_balance(&self, filter: &LabelFilter) -> Money {
+        let (from, to) = self.next_month();
+
+        self.forecast(Scenario::Expected)
+            .total_between(from, to, |change| {
+                matches!(change.kind, FlowKind::Income | FlowKind::Cost)
+                    && filter.matches(&change.labels)
+            })
+    }
+
     /// Just draws the pop-up windows.
     fn draw_windows(&mut self, ctx: &egui::Context) {
         if let Some(win) = self.new_subscription_window.as_mut() {
@@ -261,56 +1173,1594 @@ impl App {
 
                 self.save_data();
 
-                self.new_expense_window = None;
-            } else if !show {
-                self.new_expense_window = None;
+                self.new_expense_window = None;
+            } else if !show {
+                self.new_expense_window = None;
+            }
+        }
+
+        if let Some(win) = self.new_income_window.as_mut() {
+            let mut show = true;
+
+            if let Some(result) = win.show(ctx, &mut show, &self.lang) {
+                self.incomes.insert(result.uuid(), result);
+                self.apply_tax_profiles();
+
+                self.save_data();
+
+                self.new_income_window = None;
+            } else if !show {
+                self.new_income_window = None;
+            }
+        }
+
+        if let Some(win) = self.new_p_income_window.as_mut() {
+            let mut show = true;
+
+            if let Some(result) = win.show(ctx, &mut show, &self.lang) {
+                self.p_incomes.insert(result.uuid(), result);
+
+                self.save_data();
+
+                self.new_p_income_window = None;
+            } else if !show {
+                self.new_p_income_window = None;
+            }
+        }
+
+        if let Some(win) = self.new_installment_window.as_mut() {
+            let mut show = true;
+
+            if let Some(result) = win.show(ctx, &mut show, &self.lang) {
+                self.installments.insert(result.uuid(), result);
+
+                self.save_data();
+
+                self.new_installment_window = None;
+            } else if !show {
+                self.new_installment_window = None;
+            }
+        }
+
+        if let Some(win) = self.categories_window.as_mut() {
+            let mut show = true;
+
+            if let Some(result) = win.show(ctx, &mut show, &self.lang) {
+                self.categories = result;
+                self.clear_removed_categories();
+
+                self.save_data();
+
+                self.categories_window = None;
+            } else if !show {
+                self.categories_window = None;
+            }
+        }
+
+        if let Some(win) = self.new_account_window.as_mut() {
+            let mut show = true;
+
+            if let Some(result) = win.show(ctx, &mut show, &self.lang) {
+                self.accounts.insert(result.uuid(), result);
+
+                self.save_data();
+
+                self.new_account_window = None;
+            } else if !show {
+                self.new_account_window = None;
+            }
+        }
+
+        if let Some(win) = self.new_budget_window.as_mut() {
+            let mut show = true;
+
+            if let Some(result) = win.show(ctx, &mut show, &self.lang) {
+                // There can only be one budget per category
+                self.budgets
+                    .retain(|_, budget| budget.category != result.category);
+                self.budgets.insert(result.uuid(), result);
+
+                self.save_data();
+
+                self.new_budget_window = None;
+            } else if !show {
+                self.new_budget_window = None;
+            }
+        }
+
+        if let Some(win) = self.new_member_window.as_mut() {
+            let mut show = true;
+
+            if let Some(result) = win.show(ctx, &mut show, &self.lang) {
+                self.members.insert(result.uuid(), result);
+
+                self.save_data();
+
+                self.new_member_window = None;
+            } else if !show {
+                self.new_member_window = None;
+            }
+        }
+
+        if let Some((uuid, win)) = self.split_window.as_mut() {
+            let mut show = true;
+
+            if let Some(split) = win.show(ctx, &mut show, &self.lang) {
+                if let Some(subscription) = self.subscriptions.get_mut(uuid) {
+                    subscription.set_split(split);
+                } else if let Some(expense) = self.fixed_expenses.get_mut(uuid) {
+                    expense.split = split;
+                }
+
+                self.save_data();
+
+                self.split_window = None;
+            } else if !show {
+                self.split_window = None;
+            }
+        }
+
+        if let Some(win) = self.new_loan_window.as_mut() {
+            let mut show = true;
+
+            if let Some(result) = win.show(ctx, &mut show, &self.lang) {
+                self.loans.insert(result.uuid(), result);
+
+                self.save_data();
+
+                self.new_loan_window = None;
+            } else if !show {
+                self.new_loan_window = None;
+            }
+        }
+
+        if let Some(win) = self.loan_schedule_window.as_mut() {
+            let mut show = true;
+
+            win.show(ctx, &mut show, &self.lang);
+
+            if !show {
+                self.loan_schedule_window = None;
+            }
+        }
+
+        if let Some(win) = self.debt_planner_window.as_mut() {
+            let mut show = true;
+
+            win.show(ctx, &mut show, &self.lang);
+
+            if !show {
+                self.debt_planner_window = None;
+            }
+        }
+
+        if let Some(win) = self.new_goal_window.as_mut() {
+            let mut show = true;
+
+            if let Some(result) = win.show(ctx, &mut show, &self.lang) {
+                self.goals.insert(result.uuid(), result);
+
+                self.save_data();
+
+                self.new_goal_window = None;
+            } else if !show {
+                self.new_goal_window = None;
+            }
+        }
+
+        if let Some(win) = self.new_transfer_window.as_mut() {
+            let mut show = true;
+
+            if let Some(result) = win.show(ctx, &mut show, &self.lang) {
+                self.transfers.insert(result.uuid(), result);
+
+                self.save_data();
+
+                self.new_transfer_window = None;
+            } else if !show {
+                self.new_transfer_window = None;
+            }
+        }
+
+        if let Some(win) = self.new_transaction_window.as_mut() {
+            let mut show = true;
+
+            if let Some(result) = win.show(ctx, &mut show, &self.lang) {
+                self.transactions.insert(result.uuid(), result);
+
+                self.save_data();
+
+                self.new_transaction_window = None;
+            } else if !show {
+                self.new_transaction_window = None;
+            }
+        }
+
+        if let Some((uuid, win)) = self.occurrences_window.as_mut() {
+            let mut show = true;
+
+            if let Some(overrides) = win.show(ctx, &mut show, &self.lang) {
+                if let Some(subscription) = self
+                    .subscriptions
+                    .get_mut(uuid)
+                    .or(self.incomes.get_mut(uuid))
+                {
+                    for (date, occurrence) in overrides {
+                        subscription.set_override(date, occurrence);
+                    }
+                }
+
+                self.save_data();
+
+                self.occurrences_window = None;
+            } else if !show {
+                self.occurrences_window = None;
+            }
+        }
+
+        if let Some((uuid, win)) = self.subscription_details_window.as_mut() {
+            let mut show = true;
+
+            if let Some(details) = win.show(ctx, &mut show, &self.lang) {
+                if let Some(subscription) = self.subscriptions.get_mut(uuid) {
+                    subscription.set_details(details);
+                }
+
+                self.save_data();
+
+                self.subscription_details_window = None;
+            } else if !show {
+                self.subscription_details_window = None;
+            }
+        }
+
+        if let Some((uuid, win)) = self.new_price_change_window.as_mut() {
+            let mut show = true;
+
+            if let Some((date, cost)) = win.show(ctx, &mut show, &self.lang) {
+                if let Some(subscription) = self.subscriptions.get_mut(uuid) {
+                    subscription.add_price_change(date, cost);
+                } else if let Some(income) = self.incomes.get_mut(uuid) {
+                    income.add_price_change(date, cost);
+                }
+
+                self.save_data();
+
+                self.new_price_change_window = None;
+            } else if !show {
+                self.new_price_change_window = None;
+            }
+        }
+    }
+
+    /// Draws the subscriptions table.
+    /// # Arguments
+    /// - `ui`: The [`egui::Ui`](https://docs.rs/egui/0.12.2/egui/struct.Ui.html) to draw the table into.
+    /// # Returns
+    /// - `InnerResponse<()>`: The response of the table.
+    fn subscriptions_table(&mut self, ui: &mut egui::Ui) -> InnerResponse<()> {
+        ui.vertical_centered_justified(|ui| {
+            ui.heading(t!("app.title.subscriptions", self.lang));
+            ui.separator();
+
+            ui.horizontal(|ui| {
+                ui.label(t!("app.table.search", self.lang));
+                ui.text_edit_singleline(&mut self.subscription_search);
+            });
+            ui.push_id("subscriptions", |ui| {
+                egui::ScrollArea::both()
+                    .id_source("Subscriptions scroll area")
+                    .auto_shrink([true, true])
+                    .max_height(ui.available_height() - 35.0)
+                    .show(ui, |ui| {
+                        TableBuilder::new(ui)
+                            .striped(true)
+                            .auto_shrink([true, true])
+                            .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+                            .column(
+                                Column::auto()
+                                    .at_least(100.0)
+                                    .at_most(200.0)
+                                    .resizable(true),
+                            )
+                            .column(Column::auto().at_most(120.0).resizable(true))
+                            .column(Column::auto().at_most(100.0).resizable(true))
+                            .column(
+                                Column::auto()
+                                    .at_most(200.0)
+                                    .at_least(150.0)
+                                    .resizable(true),
+                            )
+                            .column(Column::auto().at_least(50.0).at_most(250.0).resizable(true))
+                            .header(20.0, |mut header| {
+                                header.col(|ui| {
+                                    ui.heading(t!("app.table.title.concept", self.lang));
+                                });
+                                header.col(|ui| {
+                                    ui.heading(t!("app.table.title.category", self.lang));
+                                });
+                                header.col(|ui| {
+                                    ui.heading(t!("app.table.title.cost", self.lang));
+                                });
+                                header.col(|ui| {
+                                    ui.heading(t!("app.table.title.recurrence", self.lang));
+                                });
+                            })
+                            .body(|mut body| {
+                                let today = Utc::now().date_naive();
+
+                                for (uuid, subscription) in self.subscriptions.clone() {
+                                    if !subscription.matches(&self.subscription_search) {
+                                        continue;
+                                    }
+
+                                    body.row(25.0, |mut row| {
+                                        row.col(|ui| {
+                                            let selected = self
+                                                .subscription_details_window
+                                                .as_ref()
+                                                .is_some_and(|(selected, _)| *selected == uuid);
+
+                                            if ui
+                                                .selectable_label(selected, subscription.name())
+                                                .on_hover_text(t!(
+                                                    "app.table.account",
+                                                    account: &self.account_name(subscription.account()),
+                                                    self.lang
+                                                ))
+                                                .clicked()
+                                            {
+                                                self.subscription_details_window = Some((
+                                                    uuid,
+                                                    SubscriptionDetailsWindow::new(
+                                                        &subscription,
+                                                        &self.holidays,
+                                                    ),
+                                                ));
+                                            }
+                                        });
+                                        row.col(|ui| {
+                                            labels_label(ui, subscription.labels());
+                                        });
+                                        row.col(|ui| {
+                                            let cost = subscription.cost_on(today);
+                                            let label = if subscription.split().is_shared() {
+                                                ui.label(RichText::new(format!(
+                                                    "{}€ / {}€",
+                                                    subscription.split().my_share(cost),
+                                                    cost
+                                                )))
+                                                .on_hover_text(t!("app.table.my_share", self.lang))
+                                            } else {
+                                                ui.label(RichText::new(format!("{}€", cost)))
+                                            };
+
+                                            if subscription
+                                                .price_changes()
+                                                .range(today..)
+                                                .next()
+                                                .is_some()
+                                            {
+                                                label.on_hover_ui(|ui| {
+                                                    ui.label(t!(
+                                                        "app.table.price_changes",
+                                                        self.lang
+                                                    ));
+
+                                                    for (date, cost) in
+                                                        subscription.price_changes().range(today..)
+                                                    {
+                                                        ui.label(format!("{}: {}€", date, cost));
+                                                    }
+                                                });
+                                            }
+                                        });
+                                        row.col(|ui| {
+                                            ui.label(RichText::new(
+                                                subscription.recurrence().to_lang_str(&self.lang),
+                                            ))
+                                            .on_hover_text(
+                                                subscription.period_to_lang_str(&self.lang),
+                                            );
+                                        });
+                                        row.col(|ui| {
+                                            if ui
+                                                .button(t!("app.button.price_change", self.lang))
+                                                .clicked()
+                                            {
+                                                self.new_price_change_window = Some((
+                                                    uuid,
+                                                    NewPriceChangeWindow::new(&subscription),
+                                                ));
+                                            }
+
+                                            if ui
+                                                .button(t!("app.button.occurrences", self.lang))
+                                                .clicked()
+                                            {
+                                                self.occurrences_window = Some((
+                                                    uuid,
+                                                    OccurrencesWindow::new(&subscription, &self.holidays),
+                                                ));
+                                            }
+
+                                            if ui
+                                                .add_enabled(
+                                                    !self.members.is_empty(),
+                                                    egui::Button::new(t!(
+                                                        "app.button.split",
+                                                        self.lang
+                                                    )),
+                                                )
+                                                .clicked()
+                                            {
+                                                self.split_window = Some((
+                                                    uuid,
+                                                    SplitWindow::new(
+                                                        subscription.name(),
+                                                        subscription.split(),
+                                                        &self.members_list(),
+                                                    ),
+                                                ));
+                                            }
+
+                                            if ui
+                                                .button(t!("app.button.delete", self.lang))
+                                                .clicked()
+                                            {
+                                                self.subscriptions.remove(&uuid);
+                                                self.save_data();
+                                            }
+                                        });
+                                    });
+                                }
+                            });
+                    });
+            });
+
+            ui.separator();
+
+            if ui
+                .button(t!("app.button.new.subscription", self.lang))
+                .clicked()
+            {
+                self.new_subscription_window = Some(NewSubscriptionWindow::new(&self.categories, &self.accounts_list()));
+            }
+        })
+    }
+
+    /// Draws the expenses table.
+    /// # Arguments
+    /// - `ui`: The [`egui::Ui`](https://docs.rs/egui/0.12.2/egui/struct.Ui.html) to draw the table into.
+    /// # Returns
+    /// - `InnerResponse<()>`: The response of the table.
+    fn expenses_table(&mut self, ui: &mut egui::Ui) -> InnerResponse<()> {
+        ui.vertical_centered_justified(|ui| {
+            ui.heading(t!("app.title.fixed_expenses", self.lang));
+            ui.separator();
+            egui::ScrollArea::both()
+                .id_source("Expenses scroll area")
+                .auto_shrink([true, true])
+                .max_height(ui.available_height() - 35.0)
+                .show(ui, |ui| {
+                    ui.push_id("expenses", |ui| {
+                        TableBuilder::new(ui)
+                            .striped(true)
+                            .auto_shrink([true, true])
+                            .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+                            .column(
+                                Column::auto()
+                                    .at_least(100.0)
+                                    .at_most(200.0)
+                                    .resizable(true),
+                            )
+                            .column(Column::auto().at_most(120.0).resizable(true))
+                            .column(Column::auto().at_most(100.0).resizable(true))
+                            .column(
+                                Column::auto()
+                                    .at_least(150.0)
+                                    .at_most(200.0)
+                                    .resizable(true),
+                            )
+                            .column(Column::auto().at_least(50.0).at_most(200.0).resizable(true))
+                            .header(20.0, |mut header| {
+                                header.col(|ui| {
+                                    ui.heading(t!("app.table.title.concept", self.lang));
+                                });
+                                header.col(|ui| {
+                                    ui.heading(t!("app.table.title.category", self.lang));
+                                });
+                                header.col(|ui| {
+                                    ui.heading(t!("app.table.title.cost", self.lang));
+                                });
+                                header.col(|ui| {
+                                    ui.heading(t!("app.table.title.date", self.lang));
+                                });
+                            })
+                            .body(|mut body| {
+                                for (uuid, expense) in self.fixed_expenses.clone() {
+                                    body.row(25.0, |mut row| {
+                                        row.col(|ui| {
+                                            ui.label(RichText::new(expense.name())).on_hover_text(
+                                                t!(
+                                                    "app.table.account",
+                                                    account: &self.account_name(expense.account()),
+                                                    self.lang
+                                                ),
+                                            );
+                                        });
+                                        row.col(|ui| {
+                                            labels_label(ui, expense.labels());
+                                        });
+                                        row.col(|ui| {
+                                            if expense.split.is_shared() {
+                                                ui.label(RichText::new(format!(
+                                                    "{}€ / {}€",
+                                                    expense.split.my_share(expense.cost()),
+                                                    expense.cost()
+                                                )))
+                                                .on_hover_text(t!("app.table.my_share", self.lang));
+                                            } else {
+                                                ui.label(RichText::new(format!(
+                                                    "{}€",
+                                                    expense.cost()
+                                                )));
+                                            }
+                                        });
+                                        row.col(|ui| {
+                                            ui.label(RichText::new(expense.date().to_string()));
+                                        });
+                                        row.col(|ui| {
+                                            if ui
+                                                .add_enabled(
+                                                    !self.members.is_empty(),
+                                                    egui::Button::new(t!(
+                                                        "app.button.split",
+                                                        self.lang
+                                                    )),
+                                                )
+                                                .clicked()
+                                            {
+                                                self.split_window = Some((
+                                                    uuid,
+                                                    SplitWindow::new(
+                                                        expense.name(),
+                                                        &expense.split,
+                                                        &self.members_list(),
+                                                    ),
+                                                ));
+                                            }
+
+                                            if ui
+                                                .button(t!("app.button.delete", self.lang))
+                                                .clicked()
+                                            {
+                                                self.fixed_expenses.remove(&uuid);
+                                                self.save_data();
+                                            }
+                                        });
+                                    });
+                                }
+                            });
+                    });
+                });
+            ui.separator();
+
+            if ui
+                .button(t!("app.button.new.fixed_expense", self.lang))
+                .clicked()
+            {
+                self.new_expense_window = Some(NewExpenseWindow::new(
+                    &self.categories,
+                    &self.accounts_list(),
+                ));
+            }
+        })
+    }
+
+    /// Draws the installment plans table.
+    /// # Arguments
+    /// - `ui`: The [`egui::Ui`](https://docs.rs/egui/0.12.2/egui/struct.Ui.html) to draw the table into.
+    /// # Returns
+    /// - `InnerResponse<()>`: The response of the table.
+    fn installments_table(&mut self, ui: &mut egui::Ui) -> InnerResponse<()> {
+        let today = Utc::now().date_naive();
+
+        ui.vertical_centered_justified(|ui| {
+            ui.heading(t!("app.title.installments", self.lang));
+            ui.separator();
+            egui::ScrollArea::both()
+                .id_source("Installments scroll area")
+                .auto_shrink([true, true])
+                .max_height(ui.available_height() - 35.0)
+                .show(ui, |ui| {
+                    ui.push_id("installments", |ui| {
+                        TableBuilder::new(ui)
+                            .striped(true)
+                            .auto_shrink([true, true])
+                            .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+                            .column(
+                                Column::auto()
+                                    .at_least(100.0)
+                                    .at_most(200.0)
+                                    .resizable(true),
+                            )
+                            .column(Column::auto().at_most(120.0).resizable(true))
+                            .column(Column::auto().at_most(100.0).resizable(true))
+                            .column(
+                                Column::auto()
+                                    .at_least(150.0)
+                                    .at_most(200.0)
+                                    .resizable(true),
+                            )
+                            .column(Column::auto().at_least(50.0).at_most(100.0).resizable(true))
+                            .header(20.0, |mut header| {
+                                header.col(|ui| {
+                                    ui.heading(t!("app.table.title.concept", self.lang));
+                                });
+                                header.col(|ui| {
+                                    ui.heading(t!("app.table.title.category", self.lang));
+                                });
+                                header.col(|ui| {
+                                    ui.heading(t!("app.table.title.total", self.lang));
+                                });
+                                header.col(|ui| {
+                                    ui.heading(t!("app.table.title.remaining", self.lang));
+                                });
+                            })
+                            .body(|mut body| {
+                                for (uuid, installment) in self.installments.clone() {
+                                    body.row(25.0, |mut row| {
+                                        row.col(|ui| {
+                                            ui.label(RichText::new(installment.name())).on_hover_text(t!(
+                                                "app.table.account",
+                                                account: &self.account_name(installment.account()),
+                                                self.lang
+                                            ));
+                                        });
+                                        row.col(|ui| {
+                                            labels_label(ui, installment.labels());
+                                        });
+                                        row.col(|ui| {
+                                            ui.label(RichText::new(format!(
+                                                "{}€",
+                                                installment.total()
+                                            )));
+                                        });
+                                        row.col(|ui| {
+                                            if installment.is_finished(today) {
+                                                ui.label(
+                                                    RichText::new(t!(
+                                                        "app.table.finished",
+                                                        self.lang
+                                                    ))
+                                                    .color(Color32::GREEN),
+                                                );
+                                            } else {
+                                                let paid =
+                                                    installment.paid_payments(today).to_string();
+                                                let payments = installment.payments().to_string();
+
+                                                ui.label(RichText::new(format!(
+                                                    "{}€",
+                                                    installment.remaining(today)
+                                                )))
+                                                .on_hover_text(t!(
+                                                    "app.table.payments_made",
+                                                    paid: &paid,
+                                                    payments: &payments,
+                                                    self.lang
+                                                ));
+                                            }
+                                        });
+                                        row.col(|ui| {
+                                            if ui
+                                                .button(t!("app.button.delete", self.lang))
+                                                .clicked()
+                                            {
+                                                self.installments.remove(&uuid);
+                                                self.save_data();
+                                            }
+                                        });
+                                    });
+                                }
+                            });
+                    });
+                });
+            ui.separator();
+
+            if ui
+                .button(t!("app.button.new.installment", self.lang))
+                .clicked()
+            {
+                self.new_installment_window = Some(NewInstallmentWindow::new(&self.categories, &self.accounts_list()));
+            }
+        })
+    }
+
+    /// Draws the loans table, with the monthly payment and the outstanding balance of each loan.
+    /// # Arguments
+    /// - `ui`: The [`egui::Ui`](https://docs.rs/egui/0.12.2/egui/struct.Ui.html) to draw the table into.
+    /// # Returns
+    /// - `InnerResponse<()>`: The response of the table.
+    fn loans_table(&mut self, ui: &mut egui::Ui) -> InnerResponse<()> {
+        let today = Utc::now().date_naive();
+
+        ui.vertical_centered_justified(|ui| {
+            ui.heading(t!("app.title.loans", self.lang));
+            ui.separator();
+            egui::ScrollArea::both()
+                .id_source("Loans scroll area")
+                .auto_shrink([true, true])
+                .max_height(ui.available_height() - 35.0)
+                .show(ui, |ui| {
+                    ui.push_id("loans", |ui| {
+                        TableBuilder::new(ui)
+                            .striped(true)
+                            .auto_shrink([true, true])
+                            .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+                            .column(
+                                Column::auto()
+                                    .at_least(100.0)
+                                    .at_most(200.0)
+                                    .resizable(true),
+                            )
+                            .column(Column::auto().at_most(120.0).resizable(true))
+                            .column(Column::auto().at_most(100.0).resizable(true))
+                            .column(Column::auto().at_most(100.0).resizable(true))
+                            .column(Column::auto().at_least(50.0).at_most(200.0).resizable(true))
+                            .header(20.0, |mut header| {
+                                header.col(|ui| {
+                                    ui.heading(t!("app.table.title.concept", self.lang));
+                                });
+                                header.col(|ui| {
+                                    ui.heading(t!("app.table.title.category", self.lang));
+                                });
+                                header.col(|ui| {
+                                    ui.heading(t!("app.table.title.payment", self.lang));
+                                });
+                                header.col(|ui| {
+                                    ui.heading(t!("app.table.title.outstanding", self.lang));
+                                });
+                            })
+                            .body(|mut body| {
+                                for (uuid, loan) in self.loans.clone() {
+                                    body.row(25.0, |mut row| {
+                                        row.col(|ui| {
+                                            ui.label(RichText::new(loan.name())).on_hover_text(t!(
+                                                "app.table.account",
+                                                account: &self.account_name(loan.account()),
+                                                self.lang
+                                            ));
+                                        });
+                                        row.col(|ui| {
+                                            labels_label(ui, loan.labels());
+                                        });
+                                        row.col(|ui| {
+                                            ui.label(RichText::new(format!(
+                                                "{}€",
+                                                loan.monthly_payment()
+                                            )));
+                                        });
+                                        row.col(|ui| {
+                                            if loan.is_finished(today) {
+                                                ui.label(
+                                                    RichText::new(t!(
+                                                        "app.table.finished",
+                                                        self.lang
+                                                    ))
+                                                    .color(Color32::GREEN),
+                                                );
+                                            } else {
+                                                let end = loan
+                                                    .last_payment()
+                                                    .format("%d/%m/%Y")
+                                                    .to_string();
+                                                let interest = format!("{}", loan.total_interest());
+
+                                                ui.label(RichText::new(format!(
+                                                    "{}€",
+                                                    loan.balance_on(today)
+                                                )))
+                                                .on_hover_text(t!(
+                                                    "app.table.loan_end",
+                                                    end: &end,
+                                                    interest: &interest,
+                                                    self.lang
+                                                ));
+                                            }
+                                        });
+                                        row.col(|ui| {
+                                            if ui
+                                                .button(t!("app.button.schedule", self.lang))
+                                                .clicked()
+                                            {
+                                                self.loan_schedule_window =
+                                                    Some(LoanScheduleWindow::new(&loan));
+                                            }
+
+                                            if ui
+                                                .button(t!("app.button.delete", self.lang))
+                                                .clicked()
+                                            {
+                                                self.loans.remove(&uuid);
+                                                self.save_data();
+                                            }
+                                        });
+                                    });
+                                }
+                            });
+                    });
+                });
+            ui.separator();
+
+            ui.horizontal(|ui| {
+                if ui.button(t!("app.button.new.loan", self.lang)).clicked() {
+                    self.new_loan_window =
+                        Some(NewLoanWindow::new(&self.categories, &self.accounts_list()));
+                }
+
+                if ui
+                    .add_enabled(
+                        !self.loans.is_empty(),
+                        egui::Button::new(t!("app.button.payoff_planner", self.lang)),
+                    )
+                    .clicked()
+                {
+                    let loans: Vec<Loan> = self.loans.values().cloned().collect();
+                    self.debt_planner_window = Some(DebtPlannerWindow::new(
+                        &loans,
+                        self.monthly_balance(&LabelFilter::default()),
+                    ));
+                }
+            });
+        })
+    }
+
+    /// Draws the accounts table.
+    /// # Arguments
+    /// - `ui`: The [`egui::Ui`](https://docs.rs/egui/0.12.2/egui/struct.Ui.html) to draw the table into.
+    /// # Returns
+    /// - `InnerResponse<()>`: The response of the table.
+    fn accounts_table(&mut self, ui: &mut egui::Ui) -> InnerResponse<()> {
+        ui.vertical_centered_justified(|ui| {
+            ui.heading(t!("app.title.accounts", self.lang));
+            ui.separator();
+            egui::ScrollArea::both()
+                .id_source("Accounts scroll area")
+                .auto_shrink([true, true])
+                .max_height(ui.available_height() - 35.0)
+                .show(ui, |ui| {
+                    ui.push_id("accounts", |ui| {
+                        TableBuilder::new(ui)
+                            .striped(true)
+                            .auto_shrink([true, true])
+                            .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+                            .column(
+                                Column::auto()
+                                    .at_least(100.0)
+                                    .at_most(200.0)
+                                    .resizable(true),
+                            )
+                            .column(Column::auto().at_least(100.0).resizable(true))
+                            .column(Column::auto().at_least(150.0).resizable(true))
+                            .column(Column::auto().at_least(50.0).at_most(100.0).resizable(true))
+                            .header(20.0, |mut header| {
+                                header.col(|ui| {
+                                    ui.heading(t!("app.table.title.concept", self.lang));
+                                });
+                                header.col(|ui| {
+                                    ui.heading(t!("app.table.title.balance", self.lang));
+                                });
+                                header.col(|ui| {
+                                    ui.heading(t!("app.table.title.interest", self.lang));
+                                });
+                            })
+                            .body(|mut body| {
+                                body.row(25.0, |mut row| {
+                                    row.col(|ui| {
+                                        ui.label(RichText::new(self.account_name(None)));
+                                    });
+                                    row.col(|ui| {
+                                        ui.label(RichText::new(format!(
+                                            "{}€",
+                                            self.initial_savings
+                                        )));
+                                    });
+                                    row.col(|ui| {
+                                        if interest_editor(
+                                            ui,
+                                            "Main account compounding",
+                                            &mut self.savings_interest,
+                                            &self.lang,
+                                        ) {
+                                            self.save_data();
+                                        }
+                                    });
+                                    row.col(|ui| {
+                                        ui.spacing();
+                                    });
+                                });
+
+                                for account in self.accounts_list() {
+                                    let uuid = account.uuid();
+
+                                    body.row(25.0, |mut row| {
+                                        row.col(|ui| {
+                                            ui.label(RichText::new(account.name()));
+                                        });
+                                        row.col(|ui| {
+                                            if let Some(account) = self.accounts.get_mut(&uuid) {
+                                                let prev = account.balance;
+
+                                                ui.add(
+                                                    egui::DragValue::from_get_set(|value| {
+                                                        if let Some(value) = value {
+                                                            account.balance =
+                                                                Money::from_f64(value);
+                                                        }
+
+                                                        account.balance.to_f64()
+                                                    })
+                                                    .speed(0.01)
+                                                    .max_decimals(2)
+                                                    .min_decimals(2)
+                                                    .suffix(" €"),
+                                                );
+
+                                                if prev != account.balance {
+                                                    self.save_data();
+                                                }
+                                            }
+                                        });
+                                        row.col(|ui| {
+                                            if let Some(account) = self.accounts.get_mut(&uuid) {
+                                                if interest_editor(
+                                                    ui,
+                                                    &format!("{} compounding", uuid),
+                                                    &mut account.interest,
+                                                    &self.lang,
+                                                ) {
+                                                    self.save_data();
+                                                }
+                                            }
+                                        });
+                                        row.col(|ui| {
+                                            if ui
+                                                .button(t!("app.button.delete", self.lang))
+                                                .clicked()
+                                            {
+                                                self.remove_account(uuid);
+                                                self.save_data();
+                                            }
+                                        });
+                                    });
+                                }
+                            });
+                    });
+                });
+            ui.separator();
+
+            if ui.button(t!("app.button.new.account", self.lang)).clicked() {
+                self.new_account_window = Some(NewAccountWindow::default());
+            }
+        })
+    }
+
+    /// Draws the transfers table.
+    /// # Arguments
+    /// - `ui`: The [`egui::Ui`](https://docs.rs/egui/0.12.2/egui/struct.Ui.html) to draw the table into.
+    /// # Returns
+    /// - `InnerResponse<()>`: The response of the table.
+    fn transfers_table(&mut self, ui: &mut egui::Ui) -> InnerResponse<()> {
+        ui.vertical_centered_justified(|ui| {
+            ui.heading(t!("app.title.transfers", self.lang));
+            ui.separator();
+            egui::ScrollArea::both()
+                .id_source("Transfers scroll area")
+                .auto_shrink([true, true])
+                .max_height(ui.available_height() - 35.0)
+                .show(ui, |ui| {
+                    ui.push_id("transfers", |ui| {
+                        TableBuilder::new(ui)
+                            .striped(true)
+                            .auto_shrink([true, true])
+                            .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+                            .column(
+                                Column::auto()
+                                    .at_least(100.0)
+                                    .at_most(200.0)
+                                    .resizable(true),
+                            )
+                            .column(Column::auto().at_most(200.0).resizable(true))
+                            .column(Column::auto().at_most(100.0).resizable(true))
+                            .column(
+                                Column::auto()
+                                    .at_most(200.0)
+                                    .at_least(150.0)
+                                    .resizable(true),
+                            )
+                            .column(Column::auto().at_least(50.0).at_most(100.0).resizable(true))
+                            .header(20.0, |mut header| {
+                                header.col(|ui| {
+                                    ui.heading(t!("app.table.title.concept", self.lang));
+                                });
+                                header.col(|ui| {
+                                    ui.heading(t!("app.table.title.accounts", self.lang));
+                                });
+                                header.col(|ui| {
+                                    ui.heading(t!("app.table.title.amount", self.lang));
+                                });
+                                header.col(|ui| {
+                                    ui.heading(t!("app.table.title.recurrence", self.lang));
+                                });
+                            })
+                            .body(|mut body| {
+                                for (uuid, transfer) in self.transfers.clone() {
+                                    body.row(25.0, |mut row| {
+                                        row.col(|ui| {
+                                            ui.label(RichText::new(transfer.name()));
+                                        });
+                                        row.col(|ui| {
+                                            ui.label(RichText::new(format!(
+                                                "{} → {}",
+                                                self.account_name(transfer.from()),
+                                                self.account_name(transfer.to())
+                                            )));
+                                        });
+                                        row.col(|ui| {
+                                            ui.label(RichText::new(format!(
+                                                "{}€",
+                                                transfer.amount()
+                                            )));
+                                        });
+                                        row.col(|ui| {
+                                            ui.label(RichText::new(
+                                                transfer.recurrence().to_lang_str(&self.lang),
+                                            ));
+                                        });
+                                        row.col(|ui| {
+                                            if ui
+                                                .button(t!("app.button.delete", self.lang))
+                                                .clicked()
+                                            {
+                                                self.transfers.remove(&uuid);
+                                                self.save_data();
+                                            }
+                                        });
+                                    });
+                                }
+                            });
+                    });
+                });
+            ui.separator();
+
+            if ui
+                .button(t!("app.button.new.transfer", self.lang))
+                .clicked()
+            {
+                self.new_transfer_window = Some(NewTransferWindow::new(&self.accounts_list()));
+            }
+        })
+    }
+
+    /// Draws the table of actual transactions.
+    /// # Arguments
+    /// - `ui`: The [`egui::Ui`](https://docs.rs/egui/0.12.2/egui/struct.Ui.html) to draw the table into.
+    /// # Returns
+    /// - `InnerResponse<()>`: The response of the table.
+    fn transactions_table(&mut self, ui: &mut egui::Ui) -> InnerResponse<()> {
+        ui.vertical_centered_justified(|ui| {
+            ui.heading(t!("app.title.transactions", self.lang));
+            ui.separator();
+            egui::ScrollArea::both()
+                .id_source("Transactions scroll area")
+                .auto_shrink([true, true])
+                .max_height(ui.available_height() - 35.0)
+                .show(ui, |ui| {
+                    ui.push_id("transactions", |ui| {
+                        TableBuilder::new(ui)
+                            .striped(true)
+                            .auto_shrink([true, true])
+                            .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+                            .column(Column::auto().at_least(100.0).resizable(true))
+                            .column(
+                                Column::auto()
+                                    .at_least(100.0)
+                                    .at_most(200.0)
+                                    .resizable(true),
+                            )
+                            .column(Column::auto().at_most(100.0).resizable(true))
+                            .column(Column::auto().at_least(50.0).at_most(100.0).resizable(true))
+                            .header(20.0, |mut header| {
+                                header.col(|ui| {
+                                    ui.heading(t!("app.table.title.date", self.lang));
+                                });
+                                header.col(|ui| {
+                                    ui.heading(t!("app.table.title.concept", self.lang));
+                                });
+                                header.col(|ui| {
+                                    ui.heading(t!("app.table.title.amount", self.lang));
+                                });
+                            })
+                            .body(|mut body| {
+                                let mut transactions: Vec<Transaction> =
+                                    self.transactions.values().cloned().collect();
+                                transactions.sort_by_key(|t| std::cmp::Reverse(t.date()));
+
+                                for transaction in transactions {
+                                    body.row(25.0, |mut row| {
+                                        row.col(|ui| {
+                                            ui.label(RichText::new(transaction.date().to_string()));
+                                        });
+                                        row.col(|ui| {
+                                            let entry = transaction
+                                                .entry()
+                                                .and_then(|entry| self.entry_name(entry))
+                                                .unwrap_or_else(|| {
+                                                    t!("window.transaction.unplanned", self.lang)
+                                                });
+
+                                            ui.label(RichText::new(transaction.description()))
+                                                .on_hover_text(t!(
+                                                    "app.table.transaction",
+                                                    account: &self
+                                                        .account_name(transaction.account()),
+                                                    entry: &entry,
+                                                    self.lang
+                                                ));
+                                        });
+                                        row.col(|ui| {
+                                            ui.label(
+                                                RichText::new(format!(
+                                                    "{:+}€",
+                                                    transaction.amount()
+                                                ))
+                                                .color(if transaction.amount().is_negative() {
+                                                    Color32::RED
+                                                } else {
+                                                    Color32::GREEN
+                                                }),
+                                            );
+                                        });
+                                        row.col(|ui| {
+                                            if ui
+                                                .button(t!("app.button.delete", self.lang))
+                                                .clicked()
+                                            {
+                                                self.transactions.remove(&transaction.uuid());
+                                                self.save_data();
+                                            }
+                                        });
+                                    });
+                                }
+                            });
+                    });
+                });
+            ui.separator();
+
+            if ui
+                .button(t!("app.button.new.transaction", self.lang))
+                .clicked()
+            {
+                self.new_transaction_window = Some(NewTransactionWindow::new(
+                    &self.accounts_list(),
+                    &self.planned_entries(),
+                ));
+            }
+        })
+    }
+
+    /// Draws the planned vs actual table, per entry or per month.
+    /// # Arguments
+    /// - `ui`: The [`egui::Ui`](https://docs.rs/egui/0.12.2/egui/struct.Ui.html) to draw the table into.
+    /// # Returns
+    /// - `InnerResponse<()>`: The response of the table.
+    fn variance_table(&mut self, ui: &mut egui::Ui) -> InnerResponse<()> {
+        ui.vertical_centered_justified(|ui| {
+            ui.heading(t!("app.title.variance", self.lang));
+            ui.horizontal(|ui| {
+                ui.selectable_value(
+                    &mut self.show_variance_per_month,
+                    false,
+                    t!("app.variance.per_entry", self.lang),
+                );
+                ui.selectable_value(
+                    &mut self.show_variance_per_month,
+                    true,
+                    t!("app.variance.per_month", self.lang),
+                );
+            });
+            ui.separator();
+
+            let rows: Vec<(String, Money, Money)> = if self.show_variance_per_month {
+                self.variance_per_month()
+                    .into_iter()
+                    .map(|(month, planned, actual)| {
+                        (month.format("%m/%Y").to_string(), planned, actual)
+                    })
+                    .collect()
+            } else {
+                self.variance_per_entry()
+            };
+
+            egui::ScrollArea::both()
+                .id_source("Variance scroll area")
+                .auto_shrink([true, true])
+                .max_height(ui.available_height() - 35.0)
+                .show(ui, |ui| {
+                    ui.push_id("variance", |ui| {
+                        TableBuilder::new(ui)
+                            .striped(true)
+                            .auto_shrink([true, true])
+                            .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+                            .column(
+                                Column::auto()
+                                    .at_least(100.0)
+                                    .at_most(200.0)
+                                    .resizable(true),
+                            )
+                            .column(Column::auto().at_most(100.0).resizable(true))
+                            .column(Column::auto().at_most(100.0).resizable(true))
+                            .column(Column::auto().at_most(100.0).resizable(true))
+                            .header(20.0, |mut header| {
+                                header.col(|ui| {
+                                    ui.heading(if self.show_variance_per_month {
+                                        t!("app.table.title.month", self.lang)
+                                    } else {
+                                        t!("app.table.title.concept", self.lang)
+                                    });
+                                });
+                                header.col(|ui| {
+                                    ui.heading(t!("app.table.title.planned", self.lang));
+                                });
+                                header.col(|ui| {
+                                    ui.heading(t!("app.table.title.actual", self.lang));
+                                });
+                                header.col(|ui| {
+                                    ui.heading(t!("app.table.title.variance", self.lang));
+                                });
+                            })
+                            .body(|mut body| {
+                                for (name, planned, actual) in rows {
+                                    let variance = actual - planned;
+
+                                    body.row(25.0, |mut row| {
+                                        row.col(|ui| {
+                                            ui.label(RichText::new(name));
+                                        });
+                                        row.col(|ui| {
+                                            ui.label(RichText::new(format!("{:+}€", planned)));
+                                        });
+                                        row.col(|ui| {
+                                            ui.label(RichText::new(format!("{:+}€", actual)));
+                                        });
+                                        row.col(|ui| {
+                                            ui.label(
+                                                RichText::new(format!("{:+}€", variance)).color(
+                                                    if variance.is_negative() {
+                                                        Color32::RED
+                                                    } else {
+                                                        Color32::GREEN
+                                                    },
+                                                ),
+                                            );
+                                        });
+                                    });
+                                }
+                            });
+                    });
+                });
+        })
+    }
+
+    /// Draws the budgets table, with the planned spending of the current month.
+    /// # Arguments
+    /// - `ui`: The [`egui::Ui`](https://docs.rs/egui/0.12.2/egui/struct.Ui.html) to draw the table into.
+    /// # Returns
+    /// - `InnerResponse<()>`: The response of the table.
+    fn budgets_table(&mut self, ui: &mut egui::Ui) -> InnerResponse<()> {
+        let today = Utc::now().date_naive();
+        let from = NaiveDate::from_ymd_opt(today.year(), today.month(), 1).unwrap();
+        let to = clamp_to_month(today.year(), today.month(), 31);
+
+        ui.vertical_centered_justified(|ui| {
+            ui.heading(t!("app.title.budgets", self.lang));
+            ui.separator();
+            egui::ScrollArea::both()
+                .id_source("Budgets scroll area")
+                .auto_shrink([true, true])
+                .max_height(ui.available_height() - 35.0)
+                .show(ui, |ui| {
+                    ui.push_id("budgets", |ui| {
+                        TableBuilder::new(ui)
+                            .striped(true)
+                            .auto_shrink([true, true])
+                            .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+                            .column(
+                                Column::auto()
+                                    .at_least(100.0)
+                                    .at_most(200.0)
+                                    .resizable(true),
+                            )
+                            .column(Column::auto().at_least(100.0).resizable(true))
+                            .column(Column::auto().at_most(100.0).resizable(true))
+                            .column(Column::auto().at_least(50.0).at_most(100.0).resizable(true))
+                            .header(20.0, |mut header| {
+                                header.col(|ui| {
+                                    ui.heading(t!("app.table.title.category", self.lang));
+                                });
+                                header.col(|ui| {
+                                    ui.heading(t!("app.table.title.limit", self.lang));
+                                });
+                                header.col(|ui| {
+                                    ui.heading(t!("app.table.title.planned", self.lang));
+                                });
+                            })
+                            .body(|mut body| {
+                                for budget in self.budgets_list() {
+                                    let uuid = budget.uuid();
+                                    let spent = self.planned_spending(&budget.category, from, to);
+
+                                    body.row(25.0, |mut row| {
+                                        row.col(|ui| {
+                                            ui.label(RichText::new(budget.category()));
+                                        });
+                                        row.col(|ui| {
+                                            if let Some(budget) = self.budgets.get_mut(&uuid) {
+                                                let prev = budget.limit;
+
+                                                ui.add(
+                                                    egui::DragValue::from_get_set(|value| {
+                                                        if let Some(value) = value {
+                                                            budget.limit =
+                                                                Money::from_f64(value.max(0.0));
+                                                        }
+
+                                                        budget.limit.to_f64()
+                                                    })
+                                                    .speed(0.01)
+                                                    .max_decimals(2)
+                                                    .min_decimals(2)
+                                                    .suffix(" €"),
+                                                );
+
+                                                if prev != budget.limit {
+                                                    self.save_data();
+                                                }
+                                            }
+                                        });
+                                        row.col(|ui| {
+                                            let text = RichText::new(format!("{}€", spent));
+
+                                            ui.label(match budget.status(spent) {
+                                                BudgetStatus::Ok => text,
+                                                BudgetStatus::Warning => text.color(AMBER),
+                                                BudgetStatus::Exceeded => text.color(Color32::RED),
+                                            });
+                                        });
+                                        row.col(|ui| {
+                                            if ui
+                                                .button(t!("app.button.delete", self.lang))
+                                                .clicked()
+                                            {
+                                                self.budgets.remove(&uuid);
+                                                self.save_data();
+                                            }
+                                        });
+                                    });
+                                }
+                            });
+                    });
+                });
+            ui.separator();
+
+            if ui.button(t!("app.button.new.budget", self.lang)).clicked() {
+                self.new_budget_window = Some(NewBudgetWindow::new(&self.categories));
+            }
+        })
+    }
+
+    /// Draws the warnings of the budgets that are about to be exceeded or are exceeded in the next
+    /// months.
+    /// # Arguments
+    /// - `ui`: The [`egui::Ui`](https://docs.rs/egui/0.12.2/egui/struct.Ui.html) to draw the table into.
+    /// # Returns
+    /// - `InnerResponse<()>`: The response of the table.
+    fn budget_warnings_table(&mut self, ui: &mut egui::Ui) -> InnerResponse<()> {
+        ui.vertical_centered_justified(|ui| {
+            ui.heading(t!("app.title.budget_warnings", self.lang));
+            ui.separator();
+
+            let warnings = self.budget_warnings();
+
+            if warnings.is_empty() {
+                ui.label(t!("app.budget.no_warnings", self.lang));
+            }
+
+            egui::ScrollArea::both()
+                .id_source("Budget warnings scroll area")
+                .auto_shrink([true, true])
+                .max_height(ui.available_height() - 35.0)
+                .show(ui, |ui| {
+                    ui.push_id("budget_warnings", |ui| {
+                        TableBuilder::new(ui)
+                            .striped(true)
+                            .auto_shrink([true, true])
+                            .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+                            .column(
+                                Column::auto()
+                                    .at_least(100.0)
+                                    .at_most(200.0)
+                                    .resizable(true),
+                            )
+                            .column(Column::auto().at_least(100.0).resizable(true))
+                            .column(Column::remainder())
+                            .header(20.0, |mut header| {
+                                header.col(|ui| {
+                                    ui.heading(t!("app.table.title.month", self.lang));
+                                });
+                                header.col(|ui| {
+                                    ui.heading(t!("app.table.title.category", self.lang));
+                                });
+                                header.col(|ui| {
+                                    ui.heading(t!("app.table.title.planned", self.lang));
+                                });
+                            })
+                            .body(|mut body| {
+                                for (month, budget, spent, status) in warnings {
+                                    body.row(25.0, |mut row| {
+                                        row.col(|ui| {
+                                            ui.label(RichText::new(
+                                                month.format("%m/%Y").to_string(),
+                                            ));
+                                        });
+                                        row.col(|ui| {
+                                            ui.label(RichText::new(budget.category()));
+                                        });
+                                        row.col(|ui| {
+                                            ui.label(
+                                                RichText::new(t!(
+                                                    "app.budget.used",
+                                                    limit: &format!("{}", budget.limit()),
+                                                    spent: &format!("{}", spent),
+                                                    self.lang
+                                                ))
+                                                .color(if status == BudgetStatus::Exceeded {
+                                                    Color32::RED
+                                                } else {
+                                                    AMBER
+                                                }),
+                                            );
+                                        });
+                                    });
+                                }
+                            });
+                    });
+                });
+        })
+    }
+
+    /// Draws the household members table.
+    /// # Arguments
+    /// - `ui`: The [`egui::Ui`](https://docs.rs/egui/0.12.2/egui/struct.Ui.html) to draw the table into.
+    /// # Returns
+    /// - `InnerResponse<()>`: The response of the table.
+    fn members_table(&mut self, ui: &mut egui::Ui) -> InnerResponse<()> {
+        ui.vertical_centered_justified(|ui| {
+            ui.heading(t!("app.title.members", self.lang));
+            ui.separator();
+            egui::ScrollArea::both()
+                .id_source("Members scroll area")
+                .auto_shrink([true, true])
+                .max_height(ui.available_height() - 35.0)
+                .show(ui, |ui| {
+                    ui.push_id("members", |ui| {
+                        TableBuilder::new(ui)
+                            .striped(true)
+                            .auto_shrink([true, true])
+                            .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+                            .column(
+                                Column::auto()
+                                    .at_least(100.0)
+                                    .at_most(200.0)
+                                    .resizable(true),
+                            )
+                            .column(Column::auto().at_least(50.0).at_most(100.0).resizable(true))
+                            .body(|mut body| {
+                                for member in self.members_list() {
+                                    body.row(25.0, |mut row| {
+                                        row.col(|ui| {
+                                            ui.label(RichText::new(member.name()));
+                                        });
+                                        row.col(|ui| {
+                                            if ui
+                                                .button(t!("app.button.delete", self.lang))
+                                                .clicked()
+                                            {
+                                                self.remove_member(member.uuid());
+                                                self.save_data();
+                                            }
+                                        });
+                                    });
+                                }
+                            });
+                    });
+                });
+            ui.separator();
+
+            if ui.button(t!("app.button.new.member", self.lang)).clicked() {
+                self.new_member_window = Some(NewMemberWindow::default());
             }
-        }
-
-        if let Some(win) = self.new_income_window.as_mut() {
-            let mut show = true;
+        })
+    }
 
-            if let Some(result) = win.show(ctx, &mut show, &self.lang) {
-                self.incomes.insert(result.uuid(), result);
+    /// Draws who owes whom for the shared expenses of the current month.
+    /// # Arguments
+    /// - `ui`: The [`egui::Ui`](https://docs.rs/egui/0.12.2/egui/struct.Ui.html) to draw the table into.
+    /// # Returns
+    /// - `InnerResponse<()>`: The response of the table.
+    fn settlement_table(&mut self, ui: &mut egui::Ui) -> InnerResponse<()> {
+        ui.vertical_centered_justified(|ui| {
+            ui.heading(t!("app.title.settlement", self.lang));
+            ui.separator();
 
-                self.save_data();
+            let settlements = self.settlements();
 
-                self.new_income_window = None;
-            } else if !show {
-                self.new_income_window = None;
+            if settlements.is_empty() {
+                ui.label(t!("app.household.settled", self.lang));
             }
-        }
-
-        if let Some(win) = self.new_p_income_window.as_mut() {
-            let mut show = true;
-
-            if let Some(result) = win.show(ctx, &mut show, &self.lang) {
-                self.p_incomes.insert(result.uuid(), result);
-
-                self.save_data();
 
-                self.new_p_income_window = None;
-            } else if !show {
-                self.new_p_income_window = None;
-            }
-        }
+            egui::ScrollArea::both()
+                .id_source("Settlement scroll area")
+                .auto_shrink([true, true])
+                .max_height(ui.available_height() - 35.0)
+                .show(ui, |ui| {
+                    for settlement in settlements {
+                        ui.label(t!(
+                            "app.household.owes",
+                            amount: &settlement.amount.to_string(),
+                            from: &self.member_name(settlement.from),
+                            to: &self.member_name(settlement.to),
+                            self.lang
+                        ));
+                    }
+                });
+        })
     }
 
-    /// Draws the subscriptions table.
+    /// Draws the savings goals table, with the progress of each goal and the monthly contribution
+    /// needed to reach it.
     /// # Arguments
     /// - `ui`: The [`egui::Ui`](https://docs.rs/egui/0.12.2/egui/struct.Ui.html) to draw the table into.
     /// # Returns
     /// - `InnerResponse<()>`: The response of the table.
-    fn subscriptions_table(&mut self, ui: &mut egui::Ui) -> InnerResponse<()> {
+    fn goals_table(&mut self, ui: &mut egui::Ui) -> InnerResponse<()> {
+        let today = Utc::now().date_naive();
+        let monthly = self.monthly_balance(&LabelFilter::default());
+
+        let mut goals: Vec<Goal> = self.goals.values().cloned().collect();
+        goals.sort_by_key(|g| g.date());
+        let allocated = allocate_savings(&goals, |account| self.account_balance(account));
+        let funds: HashMap<Uuid, Money> = goals
+            .iter()
+            .map(|goal| {
+                let saved = allocated[&goal.uuid()];
+
+                (goal.uuid(), saved + self.goal_interest(goal, saved))
+            })
+            .collect();
+        let contributions = allocate_contributions(&goals, &funds, monthly, today);
+
         ui.vertical_centered_justified(|ui| {
-            ui.heading(t!("app.title.subscriptions", self.lang));
+            ui.heading(t!("app.title.goals", self.lang));
             ui.separator();
-            ui.push_id("subscriptions", |ui| {
-                egui::ScrollArea::both()
-                    .id_source("Subscriptions scroll area")
-                    .auto_shrink([true, true])
-                    .max_height(ui.available_height() - 35.0)
-                    .show(ui, |ui| {
+            egui::ScrollArea::both()
+                .id_source("Goals scroll area")
+                .auto_shrink([true, true])
+                .max_height(ui.available_height() - 35.0)
+                .show(ui, |ui| {
+                    ui.push_id("goals", |ui| {
                         TableBuilder::new(ui)
                             .striped(true)
                             .auto_shrink([true, true])
@@ -322,47 +2772,73 @@ impl App {
                                     .resizable(true),
                             )
                             .column(Column::auto().at_most(100.0).resizable(true))
-                            .column(
-                                Column::auto()
-                                    .at_most(200.0)
-                                    .at_least(150.0)
-                                    .resizable(true),
-                            )
+                            .column(Column::auto().at_least(100.0).resizable(true))
+                            .column(Column::auto().at_most(100.0).resizable(true))
                             .column(Column::auto().at_least(50.0).at_most(100.0).resizable(true))
                             .header(20.0, |mut header| {
                                 header.col(|ui| {
                                     ui.heading(t!("app.table.title.concept", self.lang));
                                 });
                                 header.col(|ui| {
-                                    ui.heading(t!("app.table.title.cost", self.lang));
+                                    ui.heading(t!("app.table.title.date", self.lang));
                                 });
                                 header.col(|ui| {
-                                    ui.heading(t!("app.table.title.recurrence", self.lang));
+                                    ui.heading(t!("app.table.title.progress", self.lang));
+                                });
+                                header.col(|ui| {
+                                    ui.heading(t!("app.table.title.monthly", self.lang));
                                 });
                             })
                             .body(|mut body| {
-                                for (uuid, subscription) in self.subscriptions.clone() {
+                                for goal in goals {
+                                    let saved = allocated[&goal.uuid()];
+                                    let funds = funds[&goal.uuid()];
+                                    let required = goal.required_monthly(funds, today);
+                                    let on_track =
+                                        goal.is_on_track(funds, contributions[&goal.uuid()], today);
+
                                     body.row(25.0, |mut row| {
                                         row.col(|ui| {
-                                            ui.label(RichText::new(subscription.name()));
-                                        });
-                                        row.col(|ui| {
-                                            ui.label(RichText::new(format!(
-                                                "{:.2}€",
-                                                subscription.cost()
-                                            )));
+                                            ui.label(RichText::new(goal.name())).on_hover_text(t!(
+                                                "app.table.account",
+                                                account: &self.account_name(goal.account()),
+                                                self.lang
+                                            ));
                                         });
                                         row.col(|ui| {
                                             ui.label(RichText::new(
-                                                subscription.recurrence().to_lang_str(&self.lang),
+                                                goal.date().format("%d/%m/%Y").to_string(),
                                             ));
                                         });
+                                        row.col(|ui| {
+                                            ui.add(
+                                                egui::ProgressBar::new(goal.progress(saved)).text(
+                                                    format!("{}€ / {}€", saved, goal.target()),
+                                                ),
+                                            );
+                                        });
+                                        row.col(|ui| {
+                                            ui.label(
+                                                RichText::new(format!("{}€", required)).color(
+                                                    if on_track {
+                                                        Color32::GREEN
+                                                    } else {
+                                                        Color32::RED
+                                                    },
+                                                ),
+                                            )
+                                            .on_hover_text(if on_track {
+                                                t!("app.goal.on_track", self.lang)
+                                            } else {
+                                                t!("app.goal.behind", self.lang)
+                                            });
+                                        });
                                         row.col(|ui| {
                                             if ui
                                                 .button(t!("app.button.delete", self.lang))
                                                 .clicked()
                                             {
-                                                self.subscriptions.remove(&uuid);
+                                                self.goals.remove(&goal.uuid());
                                                 self.save_data();
                                             }
                                         });
@@ -370,34 +2846,57 @@ impl App {
                                 }
                             });
                     });
-            });
-
+                });
             ui.separator();
 
-            if ui
-                .button(t!("app.button.new.subscription", self.lang))
-                .clicked()
-            {
-                self.new_subscription_window = Some(NewSubscriptionWindow::default());
+            if ui.button(t!("app.button.new.goal", self.lang)).clicked() {
+                self.new_goal_window = Some(NewGoalWindow::new(&self.accounts_list()));
             }
         })
     }
 
-    /// Draws the expenses table.
+    /// Draws the long-range projection table, with the balance at the end of each year in nominal
+    /// terms and in today's money.
     /// # Arguments
     /// - `ui`: The [`egui::Ui`](https://docs.rs/egui/0.12.2/egui/struct.Ui.html) to draw the table into.
     /// # Returns
     /// - `InnerResponse<()>`: The response of the table.
-    fn expenses_table(&mut self, ui: &mut egui::Ui) -> InnerResponse<()> {
+    fn projection_table(&mut self, ui: &mut egui::Ui) -> InnerResponse<()> {
         ui.vertical_centered_justified(|ui| {
-            ui.heading(t!("app.title.fixed_expenses", self.lang));
+            ui.heading(t!("app.title.projection", self.lang));
+            ui.horizontal(|ui| {
+                ui.label(t!("app.inflation", self.lang));
+
+                let prev = self.inflation;
+                ui.add(
+                    egui::DragValue::from_get_set(|value| {
+                        if let Some(value) = value {
+                            self.inflation = (value * 100.0).round().max(0.0) as u32;
+                        }
+
+                        self.inflation as f64 / 100.0
+                    })
+                    .speed(0.01)
+                    .max_decimals(2)
+                    .min_decimals(2)
+                    .clamp_range(0.0..=100.0)
+                    .suffix(" %"),
+                );
+
+                if prev != self.inflation {
+                    self.save_data();
+                }
+            });
             ui.separator();
+
+            let rows = self.projection();
+
             egui::ScrollArea::both()
-                .id_source("Expenses scroll area")
+                .id_source("Projection scroll area")
                 .auto_shrink([true, true])
                 .max_height(ui.available_height() - 35.0)
                 .show(ui, |ui| {
-                    ui.push_id("expenses", |ui| {
+                    ui.push_id("projection", |ui| {
                         TableBuilder::new(ui)
                             .striped(true)
                             .auto_shrink([true, true])
@@ -408,62 +2907,51 @@ impl App {
                                     .at_most(200.0)
                                     .resizable(true),
                             )
-                            .column(Column::auto().at_most(100.0).resizable(true))
-                            .column(
-                                Column::auto()
-                                    .at_least(150.0)
-                                    .at_most(200.0)
-                                    .resizable(true),
-                            )
-                            .column(Column::auto().at_least(50.0).at_most(100.0).resizable(true))
+                            .column(Column::auto().at_least(100.0).resizable(true))
+                            .column(Column::auto().at_least(100.0).resizable(true))
                             .header(20.0, |mut header| {
                                 header.col(|ui| {
-                                    ui.heading(t!("app.table.title.concept", self.lang));
+                                    ui.heading(t!("app.table.title.year", self.lang));
                                 });
                                 header.col(|ui| {
-                                    ui.heading(t!("app.table.title.cost", self.lang));
+                                    ui.heading(t!("app.table.title.nominal", self.lang));
                                 });
                                 header.col(|ui| {
-                                    ui.heading(t!("app.table.title.date", self.lang));
+                                    ui.heading(t!("app.table.title.real", self.lang))
+                                        .on_hover_text(t!("app.table.real_hint", self.lang));
                                 });
                             })
                             .body(|mut body| {
-                                for (uuid, expense) in self.fixed_expenses.clone() {
+                                for (year, nominal, real) in rows {
                                     body.row(25.0, |mut row| {
                                         row.col(|ui| {
-                                            ui.label(RichText::new(expense.name()));
-                                        });
-                                        row.col(|ui| {
-                                            ui.label(RichText::new(format!(
-                                                "{:.2}€",
-                                                expense.cost()
-                                            )));
+                                            ui.label(RichText::new(year.to_string()));
                                         });
                                         row.col(|ui| {
-                                            ui.label(RichText::new(expense.date().to_string()));
+                                            ui.label(
+                                                RichText::new(format!("{:+}€", nominal)).color(
+                                                    if nominal.is_negative() {
+                                                        Color32::RED
+                                                    } else {
+                                                        Color32::GREEN
+                                                    },
+                                                ),
+                                            );
                                         });
                                         row.col(|ui| {
-                                            if ui
-                                                .button(t!("app.button.delete", self.lang))
-                                                .clicked()
-                                            {
-                                                self.fixed_expenses.remove(&uuid);
-                                                self.save_data();
-                                            }
+                                            ui.label(RichText::new(format!("{:+}€", real)).color(
+                                                if real.is_negative() {
+                                                    Color32::RED
+                                                } else {
+                                                    Color32::GREEN
+                                                },
+                                            ));
                                         });
                                     });
                                 }
                             });
                     });
                 });
-            ui.separator();
-
-            if ui
-                .button(t!("app.button.new.fixed_expense", self.lang))
-                .clicked()
-            {
-                self.new_expense_window = Some(NewExpenseWindow::default());
-            }
         })
     }
 
@@ -472,7 +2960,7 @@ impl App {
     /// - `ui`: The [`egui::Ui`](https://docs.rs/egui/0.12.2/egui/struct.Ui.html) to draw the table into.
     /// # Returns
     /// - `InnerResponse<()>`: The response of the table.
-    fn results_table(&self, ui: &mut egui::Ui) -> InnerResponse<()> {
+    fn results_table(&mut self, ui: &mut egui::Ui) -> InnerResponse<()> {
         ui.vertical(|ui| {
             ui.add_space(20.0);
             ui.vertical_centered(|ui| {
@@ -480,6 +2968,39 @@ impl App {
             });
             ui.spacing();
 
+            ui.horizontal(|ui| {
+                let all = t!("stats.filter.all", self.lang);
+
+                ui.label(t!("stats.filter.category", self.lang));
+                egui::ComboBox::from_id_source("Stats category")
+                    .selected_text(self.stats_filter.category.clone().unwrap_or(all.clone()))
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut self.stats_filter.category, None, all.clone());
+
+                        for category in &self.categories {
+                            ui.selectable_value(
+                                &mut self.stats_filter.category,
+                                Some(category.clone()),
+                                category,
+                            );
+                        }
+                    });
+
+                ui.label(t!("stats.filter.tag", self.lang));
+                egui::ComboBox::from_id_source("Stats tag")
+                    .selected_text(self.stats_filter.tag.clone().unwrap_or(all.clone()))
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut self.stats_filter.tag, None, all.clone());
+
+                        for tag in self.tags() {
+                            ui.selectable_value(&mut self.stats_filter.tag, Some(tag.clone()), tag);
+                        }
+                    });
+            });
+
+            let year_end = NaiveDate::from_ymd_opt(Utc::now().year(), 12, 31).unwrap();
+            let (forecast, runway) = self.stats_forecast();
+
             ui.horizontal(|ui| {
                 ui.push_id("results", |ui| {
                     TableBuilder::new(ui)
@@ -500,7 +3021,7 @@ impl App {
                                 });
                                 row.col(|ui| {
                                     ui.label(
-                                        RichText::new(format!("{:+.2}€", self.monthly_costs()))
+                                        RichText::new(format!("{:+}€", self.monthly_costs()))
                                             .color(Color32::RED),
                                     );
                                 });
@@ -523,11 +3044,9 @@ impl App {
                                 row.col(|ui| {
                                     ui.label(
                                         RichText::new(format!(
-                                            "{:+.2}€",
-                                            cost_to_year_end(
-                                                self.subscriptions.clone().into_values().collect(),
-                                                self.fixed_expenses.clone().into_values().collect()
-                                            )
+                                            "{:+}€",
+                                            forecast.members_share(year_end)
+                                                - forecast.total(FlowKind::Cost, year_end)
                                         ))
                                         .color(Color32::RED),
                                     );
@@ -537,6 +3056,33 @@ impl App {
                                 });
                             });
 
+                            // The part of the costs the user pays, if they share any
+                            if self.has_shared_entries() {
+                                body.row(20.0, |mut row| {
+                                    row.col(|ui| {
+                                        ui.spacing();
+                                    });
+                                    row.col(|ui| {
+                                        ui.label(RichText::new(t!(
+                                            "stats.my_share_til_eoy",
+                                            self.lang
+                                        )));
+                                    });
+                                    row.col(|ui| {
+                                        ui.label(
+                                            RichText::new(format!(
+                                                "{:+}€",
+                                                -forecast.total(FlowKind::Cost, year_end)
+                                            ))
+                                            .color(Color32::RED),
+                                        );
+                                    });
+                                    row.col(|ui| {
+                                        ui.spacing();
+                                    });
+                                });
+                            }
+
                             body.row(20.0, |mut row| {
                                 row.col(|ui| {
                                     ui.spacing();
@@ -551,11 +3097,33 @@ impl App {
                                 row.col(|ui| {
                                     ui.label(
                                         RichText::new(format!(
-                                            "+{:.2}€",
-                                            cost_to_year_end(
-                                                self.incomes.clone().into_values().collect(),
-                                                self.p_incomes.clone().into_values().collect()
-                                            )
+                                            "{:+}€",
+                                            forecast.total(FlowKind::Income, year_end)
+                                        ))
+                                        .color(Color32::GREEN),
+                                    );
+                                });
+                                row.col(|ui| {
+                                    ui.spacing();
+                                });
+                            });
+
+                            body.row(20.0, |mut row| {
+                                row.col(|ui| {
+                                    ui.spacing();
+                                });
+                                row.col(|ui| {
+                                    ui.label(RichText::new(t!(
+                                        "stats.total_interest_til_eoy",
+                                        self.lang
+                                    )));
+                                });
+
+                                row.col(|ui| {
+                                    ui.label(
+                                        RichText::new(format!(
+                                            "{:+}€",
+                                            forecast.total(FlowKind::Interest, year_end)
                                         ))
                                         .color(Color32::GREEN),
                                     );
@@ -596,19 +3164,11 @@ impl App {
                                 });
 
                                 row.col(|ui| {
-                                    let balance = self.initial_savings
-                                        + cost_to_year_end(
-                                            self.incomes.clone().into_values().collect(),
-                                            self.p_incomes.clone().into_values().collect(),
-                                        )
-                                        - cost_to_year_end(
-                                            self.subscriptions.clone().into_values().collect(),
-                                            self.fixed_expenses.clone().into_values().collect(),
-                                        );
+                                    let balance = forecast.balance_on(year_end);
 
                                     ui.label(
-                                        RichText::new(format!("{:+.2}€", balance))
-                                            .color(if balance < 0.0 {
+                                        RichText::new(format!("{:+}€", balance))
+                                            .color(if balance.is_negative() {
                                                 Color32::RED
                                             } else {
                                                 Color32::GREEN
@@ -620,6 +3180,148 @@ impl App {
                                     ui.spacing();
                                 });
                             });
+
+                            // The balance at the end of the year if the uncertain entries turn out
+                            // badly, as expected or well
+                            if self.has_uncertain_entries() {
+                                body.row(20.0, |mut row| {
+                                    row.col(|ui| {
+                                        ui.spacing();
+                                    });
+                                    row.col(|ui| {
+                                        ui.label(RichText::new(t!(
+                                            "stats.scenarios_eoy",
+                                            self.lang
+                                        )));
+                                    });
+                                    row.col(|ui| {
+                                        ui.horizontal(|ui| {
+                                            for scenario in Scenario::ALL {
+                                                let balance = self.balance_eoy(scenario);
+
+                                                ui.label(
+                                                    RichText::new(format!("{:+}€", balance)).color(
+                                                        if balance.is_negative() {
+                                                            Color32::RED
+                                                        } else {
+                                                            Color32::GREEN
+                                                        },
+                                                    ),
+                                                )
+                                                .on_hover_text(scenario_to_lang_str(
+                                                    scenario, &self.lang,
+                                                ));
+                                            }
+                                        });
+                                    });
+                                    row.col(|ui| {
+                                        ui.spacing();
+                                    });
+                                });
+                            }
+
+                            // The lowest point of the balance, which the total at the end of the
+                            // year hides
+                            body.row(20.0, |mut row| {
+                                row.col(|ui| {
+                                    ui.spacing();
+                                });
+                                row.col(|ui| {
+                                    ui.label(RichText::new(t!("stats.lowest_balance", self.lang)));
+                                });
+                                row.col(|ui| {
+                                    let (date, balance) = forecast.lowest();
+
+                                    ui.label(
+                                        RichText::new(format!(
+                                            "{:+}€ ({})",
+                                            balance,
+                                            date.format("%d/%m/%Y")
+                                        ))
+                                        .color(
+                                            if balance.is_negative() {
+                                                Color32::RED
+                                            } else {
+                                                Color32::GREEN
+                                            },
+                                        ),
+                                    );
+                                });
+                                row.col(|ui| {
+                                    ui.spacing();
+                                });
+                            });
+
+                            body.row(20.0, |mut row| {
+                                row.col(|ui| {
+                                    ui.spacing();
+                                });
+                                row.col(|ui| {
+                                    ui.label(RichText::new(t!("stats.first_negative", self.lang)));
+                                });
+                                row.col(|ui| match forecast.first_negative() {
+                                    Some(date) => {
+                                        ui.label(
+                                            RichText::new(date.format("%d/%m/%Y").to_string())
+                                                .color(Color32::RED),
+                                        );
+                                    }
+                                    None => {
+                                        ui.label(
+                                            RichText::new(t!("stats.never", self.lang))
+                                                .color(Color32::GREEN),
+                                        );
+                                    }
+                                });
+                                row.col(|ui| {
+                                    ui.spacing();
+                                });
+                            });
+
+                            body.row(20.0, |mut row| {
+                                row.col(|ui| {
+                                    ui.spacing();
+                                });
+                                row.col(|ui| {
+                                    ui.label(RichText::new(t!("stats.runway", self.lang)));
+                                });
+                                row.col(|ui| match runway {
+                                    Some(months) => {
+                                        ui.label(
+                                            RichText::new(t!(
+                                                "stats.runway.months",
+                                                months: &months.to_string(),
+                                                self.lang
+                                            ))
+                                            .color(
+                                                if months < 3 {
+                                                    Color32::RED
+                                                } else {
+                                                    Color32::YELLOW
+                                                },
+                                            ),
+                                        );
+                                    }
+                                    None => {
+                                        let years =
+                                            months_between(Utc::now().date_naive(), self.horizon())
+                                                / 12;
+
+                                        ui.label(
+                                            RichText::new(t!(
+                                                "stats.runway.longer",
+                                                years: &years.to_string(),
+                                                self.lang
+                                            ))
+                                            .color(Color32::GREEN),
+                                        );
+                                    }
+                                });
+                                row.col(|ui| {
+                                    ui.spacing();
+                                });
+                            });
+
                             body.row(20.0, |mut row| {
                                 row.col(|ui| {
                                     ui.spacing();
@@ -631,11 +3333,11 @@ impl App {
                                 });
 
                                 row.col(|ui| {
-                                    let balance = self.monthly_balance();
+                                    let balance = self.monthly_balance(&self.stats_filter);
 
                                     ui.label(
-                                        RichText::new(format!("{:+.2}€", balance))
-                                            .color(if balance < 0.0 {
+                                        RichText::new(format!("{:+}€", balance))
+                                            .color(if balance.is_negative() {
                                                 Color32::RED
                                             } else {
                                                 Color32::GREEN
@@ -647,6 +3349,60 @@ impl App {
                                     ui.spacing();
                                 });
                             });
+
+                            // Balance of each account, now and at the end of the year
+                            if !self.accounts.is_empty() {
+                                body.row(20.0, |mut row| {
+                                    row.col(|ui| {
+                                        ui.spacing();
+                                    });
+                                    row.col(|ui| {
+                                        ui.label(
+                                            RichText::new(t!("stats.accounts", self.lang)).strong(),
+                                        );
+                                    });
+                                    row.col(|ui| {
+                                        ui.spacing();
+                                    });
+                                    row.col(|ui| {
+                                        ui.spacing();
+                                    });
+                                });
+
+                                let accounts = std::iter::once(None).chain(
+                                    self.accounts_list().into_iter().map(|a| Some(a.uuid())),
+                                );
+
+                                for account in accounts {
+                                    body.row(20.0, |mut row| {
+                                        row.col(|ui| {
+                                            ui.spacing();
+                                        });
+                                        row.col(|ui| {
+                                            ui.label(RichText::new(self.account_name(account)));
+                                        });
+                                        row.col(|ui| {
+                                            let balance = self.account_balance_eoy(account);
+
+                                            ui.label(
+                                                RichText::new(format!(
+                                                    "{:+}€ → {:+}€",
+                                                    self.account_balance(account),
+                                                    balance
+                                                ))
+                                                .color(if balance.is_negative() {
+                                                    Color32::RED
+                                                } else {
+                                                    Color32::GREEN
+                                                }),
+                                            );
+                                        });
+                                        row.col(|ui| {
+                                            ui.spacing();
+                                        });
+                                    });
+                                }
+                            }
                         });
                 });
             });
@@ -678,6 +3434,7 @@ impl App {
                                     .at_most(200.0)
                                     .resizable(true),
                             )
+                            .column(Column::auto().at_most(120.0).resizable(true))
                             .column(Column::auto().at_most(100.0).resizable(true))
                             .column(
                                 Column::auto()
@@ -685,11 +3442,14 @@ impl App {
                                     .at_least(150.0)
                                     .resizable(true),
                             )
-                            .column(Column::auto().at_least(50.0).at_most(100.0).resizable(true))
+                            .column(Column::auto().at_least(50.0).at_most(250.0).resizable(true))
                             .header(20.0, |mut header| {
                                 header.col(|ui| {
                                     ui.heading(t!("app.table.title.concept", self.lang));
                                 });
+                                header.col(|ui| {
+                                    ui.heading(t!("app.table.title.category", self.lang));
+                                });
                                 header.col(|ui| {
                                     ui.heading(t!("app.table.title.cost", self.lang));
                                 });
@@ -698,23 +3458,92 @@ impl App {
                                 });
                             })
                             .body(|mut body| {
+                                let today = Utc::now().date_naive();
+
                                 for (uuid, subscription) in self.incomes.clone() {
                                     body.row(25.0, |mut row| {
                                         row.col(|ui| {
-                                            ui.label(RichText::new(subscription.name()));
+                                            ui.label(RichText::new(subscription.name())).on_hover_text(t!(
+                                                "app.table.account",
+                                                account: &self.account_name(subscription.account()),
+                                                self.lang
+                                            ));
+                                        });
+                                        row.col(|ui| {
+                                            labels_label(ui, subscription.labels());
                                         });
                                         row.col(|ui| {
-                                            ui.label(RichText::new(format!(
-                                                "{:.2}€",
-                                                subscription.cost()
+                                            let mut label = ui.label(RichText::new(format!(
+                                                "{}€",
+                                                subscription.cost_on(today)
                                             )));
+
+                                            if let Some(profile) = subscription.tax_profile() {
+                                                label = if subscription.tax().is_some() {
+                                                    label.on_hover_text(t!(
+                                                        "app.table.gross",
+                                                        gross: &subscription.gross_on(today).to_string(),
+                                                        profile: profile,
+                                                        self.lang
+                                                    ))
+                                                } else {
+                                                    label.on_hover_text(t!(
+                                                        "app.table.missing_tax_profile",
+                                                        profile: profile,
+                                                        self.lang
+                                                    ))
+                                                };
+                                            }
+
+                                            if subscription
+                                                .price_changes()
+                                                .range(today..)
+                                                .next()
+                                                .is_some()
+                                            {
+                                                label.on_hover_ui(|ui| {
+                                                    ui.label(t!(
+                                                        "app.table.price_changes",
+                                                        self.lang
+                                                    ));
+
+                                                    for (date, cost) in
+                                                        subscription.price_changes().range(today..)
+                                                    {
+                                                        ui.label(format!("{}: {}€", date, cost));
+                                                    }
+                                                });
+                                            }
                                         });
                                         row.col(|ui| {
                                             ui.label(RichText::new(
                                                 subscription.recurrence().to_lang_str(&self.lang),
-                                            ));
+                                            ))
+                                            .on_hover_text(
+                                                subscription.period_to_lang_str(&self.lang),
+                                            );
                                         });
                                         row.col(|ui| {
+                                            if ui
+                                                .button(t!("app.button.price_change", self.lang))
+                                                .clicked()
+                                            {
+                                                self.new_price_change_window = Some((
+                                                    uuid,
+                                                    NewPriceChangeWindow::new(&subscription),
+                                                ));
+                                            }
+
+                                            if ui
+                                                .button(t!("app.button.occurrences", self.lang))
+                                                .clicked()
+                                            {
+                                                self.occurrences_window = Some((
+                                                    uuid,
+                                                    OccurrencesWindow::new(&subscription, &self.holidays),
+                                                ));
+                                            }
+
                                             if ui
                                                 .button(t!("app.button.delete", self.lang))
                                                 .clicked()
@@ -735,7 +3564,11 @@ impl App {
                 .button(t!("app.button.new.income_stream", self.lang))
                 .clicked()
             {
-                self.new_income_window = Some(NewIncomeWindow::default());
+                self.new_income_window = Some(NewIncomeWindow::new(
+                    &self.categories,
+                    &self.accounts_list(),
+                    &self.tax_profiles.names(),
+                ));
             }
         })
     }
@@ -765,6 +3598,7 @@ impl App {
                                     .at_most(200.0)
                                     .resizable(true),
                             )
+                            .column(Column::auto().at_most(120.0).resizable(true))
                             .column(Column::auto().at_most(100.0).resizable(true))
                             .column(
                                 Column::auto()
@@ -777,6 +3611,9 @@ impl App {
                                 header.col(|ui| {
                                     ui.heading(t!("app.table.title.concept", self.lang));
                                 });
+                                header.col(|ui| {
+                                    ui.heading(t!("app.table.title.category", self.lang));
+                                });
                                 header.col(|ui| {
                                     ui.heading(t!("app.table.title.cost", self.lang));
                                 });
@@ -788,13 +3625,19 @@ impl App {
                                 for (uuid, expense) in self.p_incomes.clone() {
                                     body.row(25.0, |mut row| {
                                         row.col(|ui| {
-                                            ui.label(RichText::new(expense.name()));
+                                            ui.label(RichText::new(expense.name())).on_hover_text(
+                                                t!(
+                                                    "app.table.account",
+                                                    account: &self.account_name(expense.account()),
+                                                    self.lang
+                                                ),
+                                            );
                                         });
                                         row.col(|ui| {
-                                            ui.label(RichText::new(format!(
-                                                "{:.2}€",
-                                                expense.cost()
-                                            )));
+                                            labels_label(ui, expense.labels());
+                                        });
+                                        row.col(|ui| {
+                                            ui.label(RichText::new(format!("{}€", expense.cost())));
                                         });
                                         row.col(|ui| {
                                             ui.label(RichText::new(expense.date().to_string()));
@@ -819,7 +3662,10 @@ impl App {
                 .button(t!("app.button.new_punctual_income", self.lang))
                 .clicked()
             {
-                self.new_p_income_window = Some(NewPunctualIncomeWindow::default());
+                self.new_p_income_window = Some(NewPunctualIncomeWindow::new(
+                    &self.categories,
+                    &self.accounts_list(),
+                ));
             }
         })
     }
@@ -830,14 +3676,20 @@ impl eframe::App for App {
         self.draw_windows(ctx);
 
         egui::TopBottomPanel::top("top_panel").show(ctx, |ui| {
-            ui.menu_button(t!("app.language", self.lang), |ui| {
-                let lang = self.lang.clone();
+            egui::menu::bar(ui, |ui| {
+                ui.menu_button(t!("app.language", self.lang), |ui| {
+                    let lang = self.lang.clone();
 
-                ui.radio_value(&mut self.lang, String::from("en"), t!("english", lang));
-                ui.radio_value(&mut self.lang, String::from("es"), t!("spanish", lang));
+                    ui.radio_value(&mut self.lang, String::from("en"), t!("english", lang));
+                    ui.radio_value(&mut self.lang, String::from("es"), t!("spanish", lang));
 
-                if lang != self.lang {
-                    self.save_data();
+                    if lang != self.lang {
+                        self.save_data();
+                    }
+                });
+
+                if ui.button(t!("app.categories", self.lang)).clicked() {
+                    self.categories_window = Some(CategoriesWindow::new(&self.categories));
                 }
             });
         });
@@ -879,6 +3731,20 @@ impl eframe::App for App {
                                                 row.col(|ui| {
                                                     self.expenses_table(ui);
                                                 });
+                                            });
+
+                                            body.row(200.0, |mut row| {
+                                                row.col(|ui| {
+                                                    self.installments_table(ui);
+                                                });
+
+                                                row.col(|ui| {
+                                                    ui.spacing();
+                                                });
+
+                                                row.col(|ui| {
+                                                    self.loans_table(ui);
+                                                });
                                             })
                                         });
                                 });
@@ -929,6 +3795,221 @@ impl eframe::App for App {
                         },
                     );
 
+                    ui.add_space(25.0);
+
+                    ui.collapsing(
+                        RichText::new(t!("app.collapsing.accounts", self.lang)).heading(),
+                        |ui| {
+                            ui.horizontal(|ui| {
+                                egui::ScrollArea::horizontal().show(ui, |ui| {
+                                    TableBuilder::new(ui)
+                                        .vscroll(false)
+                                        .auto_shrink([false, true])
+                                        .column(
+                                            Column::auto()
+                                                .at_least(450.0)
+                                                .clip(true)
+                                                .resizable(false),
+                                        )
+                                        .column(Column::auto().at_least(25.0))
+                                        .column(
+                                            Column::auto()
+                                                .at_least(450.0)
+                                                .clip(true)
+                                                .resizable(false),
+                                        )
+                                        .body(|mut body| {
+                                            body.row(200.0, |mut row| {
+                                                row.col(|ui| {
+                                                    self.accounts_table(ui);
+                                                });
+
+                                                row.col(|ui| {
+                                                    ui.spacing();
+                                                });
+
+                                                row.col(|ui| {
+                                                    self.transfers_table(ui);
+                                                });
+                                            });
+                                        });
+                                });
+                            });
+                        },
+                    );
+
+                    ui.add_space(25.0);
+
+                    ui.collapsing(
+                        RichText::new(t!("app.collapsing.ledger", self.lang)).heading(),
+                        |ui| {
+                            ui.horizontal(|ui| {
+                                egui::ScrollArea::horizontal().show(ui, |ui| {
+                                    TableBuilder::new(ui)
+                                        .vscroll(false)
+                                        .auto_shrink([false, true])
+                                        .column(
+                                            Column::auto()
+                                                .at_least(450.0)
+                                                .clip(true)
+                                                .resizable(false),
+                                        )
+                                        .column(Column::auto().at_least(25.0))
+                                        .column(
+                                            Column::auto()
+                                                .at_least(450.0)
+                                                .clip(true)
+                                                .resizable(false),
+                                        )
+                                        .body(|mut body| {
+                                            body.row(200.0, |mut row| {
+                                                row.col(|ui| {
+                                                    self.transactions_table(ui);
+                                                });
+
+                                                row.col(|ui| {
+                                                    ui.spacing();
+                                                });
+
+                                                row.col(|ui| {
+                                                    self.variance_table(ui);
+                                                });
+                                            });
+                                        });
+                                });
+                            });
+                        },
+                    );
+
+                    ui.add_space(25.0);
+
+                    ui.collapsing(
+                        RichText::new(t!("app.collapsing.budgets", self.lang)).heading(),
+                        |ui| {
+                            ui.horizontal(|ui| {
+                                egui::ScrollArea::horizontal().show(ui, |ui| {
+                                    TableBuilder::new(ui)
+                                        .vscroll(false)
+                                        .auto_shrink([false, true])
+                                        .column(
+                                            Column::auto()
+                                                .at_least(450.0)
+                                                .clip(true)
+                                                .resizable(false),
+                                        )
+                                        .column(Column::auto().at_least(25.0))
+                                        .column(
+                                            Column::auto()
+                                                .at_least(450.0)
+                                                .clip(true)
+                                                .resizable(false),
+                                        )
+                                        .body(|mut body| {
+                                            body.row(200.0, |mut row| {
+                                                row.col(|ui| {
+                                                    self.budgets_table(ui);
+                                                });
+
+                                                row.col(|ui| {
+                                                    ui.spacing();
+                                                });
+
+                                                row.col(|ui| {
+                                                    self.budget_warnings_table(ui);
+                                                });
+                                            });
+                                        });
+                                });
+                            });
+                        },
+                    );
+
+                    ui.add_space(25.0);
+
+                    ui.collapsing(
+                        RichText::new(t!("app.collapsing.goals_projection", self.lang)).heading(),
+                        |ui| {
+                            ui.horizontal(|ui| {
+                                egui::ScrollArea::horizontal().show(ui, |ui| {
+                                    TableBuilder::new(ui)
+                                        .vscroll(false)
+                                        .auto_shrink([false, true])
+                                        .column(
+                                            Column::auto()
+                                                .at_least(450.0)
+                                                .clip(true)
+                                                .resizable(false),
+                                        )
+                                        .column(Column::auto().at_least(25.0))
+                                        .column(
+                                            Column::auto()
+                                                .at_least(450.0)
+                                                .clip(true)
+                                                .resizable(false),
+                                        )
+                                        .body(|mut body| {
+                                            body.row(200.0, |mut row| {
+                                                row.col(|ui| {
+                                                    self.goals_table(ui);
+                                                });
+
+                                                row.col(|ui| {
+                                                    ui.spacing();
+                                                });
+
+                                                row.col(|ui| {
+                                                    self.projection_table(ui);
+                                                });
+                                            });
+                                        });
+                                });
+                            });
+                        },
+                    );
+
+                    ui.add_space(25.0);
+
+                    ui.collapsing(
+                        RichText::new(t!("app.collapsing.household", self.lang)).heading(),
+                        |ui| {
+                            ui.horizontal(|ui| {
+                                egui::ScrollArea::horizontal().show(ui, |ui| {
+                                    TableBuilder::new(ui)
+                                        .vscroll(false)
+                                        .auto_shrink([false, true])
+                                        .column(
+                                            Column::auto()
+                                                .at_least(450.0)
+                                                .clip(true)
+                                                .resizable(false),
+                                        )
+                                        .column(Column::auto().at_least(25.0))
+                                        .column(
+                                            Column::auto()
+                                                .at_least(450.0)
+                                                .clip(true)
+                                                .resizable(false),
+                                        )
+                                        .body(|mut body| {
+                                            body.row(200.0, |mut row| {
+                                                row.col(|ui| {
+                                                    self.members_table(ui);
+                                                });
+
+                                                row.col(|ui| {
+                                                    ui.spacing();
+                                                });
+
+                                                row.col(|ui| {
+                                                    self.settlement_table(ui);
+                                                });
+                                            });
+                                        });
+                                });
+                            });
+                        },
+                    );
+
                     ui.add_space(15.0);
                     ui.separator();
                     ui.add_space(15.0);
@@ -938,11 +4019,17 @@ impl eframe::App for App {
 
                         let prev = self.initial_savings;
                         ui.add(
-                            egui::DragValue::new(&mut self.initial_savings)
-                                .speed(0.01)
-                                .max_decimals(2)
-                                .min_decimals(2)
-                                .suffix(" €"),
+                            egui::DragValue::from_get_set(|value| {
+                                if let Some(value) = value {
+                                    self.initial_savings = Money::from_f64(value);
+                                }
+
+                                self.initial_savings.to_f64()
+                            })
+                            .speed(0.01)
+                            .max_decimals(2)
+                            .min_decimals(2)
+                            .suffix(" €"),
                         );
 
                         if prev != self.initial_savings {
@@ -4,8 +4,21 @@ mod windows;
 
 pub use app::App;
 pub use utils::{
-    times_until, FixedExpense, Recurrence, SimpleRecurrence, Subscription, TmpSubscription,
+    allocate_contributions, allocate_savings, clamp_to_month, compounding_to_lang_str,
+    days_in_month, default_categories, escalate, months_between, nth_to_lang_str,
+    nth_weekday_of_month, plan_payoff, scenario_to_lang_str, settle, times_until, to_real,
+    weekday_to_lang_str, Account, Budget, BudgetStatus, BusinessDayRule, Compounding, Estimate,
+    FixedExpense, FlowKind, Forecast, ForecastEntry, Goal, HolidayCalendar, Installment,
+    InterestRate, LabelFilter, Labels, Loan, LoanPayment, Member, Money, OccurrenceOverride,
+    Occurrences, PayoffPlan, PayoffStrategy, Recurrence, Scenario, Settlement, ShareRule,
+    SimpleRecurrence, Split, Subscription, SubscriptionDetails, TaxBracket, TaxProfile,
+    TaxProfiles, TmpInstallment, TmpLoan, TmpSubscription, TmpTransaction, TmpTransfer,
+    Transaction, Transfer, Uncertainty,
 };
 pub use windows::{
-    NewExpenseWindow, NewIncomeWindow, NewPunctualIncomeWindow, NewSubscriptionWindow,
+    CategoriesWindow, DebtPlannerWindow, LoanScheduleWindow, NewAccountWindow, NewBudgetWindow,
+    NewExpenseWindow, NewGoalWindow, NewIncomeWindow, NewInstallmentWindow, NewLoanWindow,
+    NewMemberWindow, NewPriceChangeWindow, NewPunctualIncomeWindow, NewSubscriptionWindow,
+    NewTransactionWindow, NewTransferWindow, OccurrencesWindow, SplitWindow,
+    SubscriptionDetailsWindow,
 };
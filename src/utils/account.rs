@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{InterestRate, Money};
+
+/// An account (e.g. a checking account or a credit card) that entries are charged to or paid into.
+///
+/// The entries that have no account are charged to the main account, whose balance is the initial
+/// savings of the app.
+#[derive(Clone, Debug, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct Account {
+    uuid: Uuid,
+    pub name: String,
+    pub balance: Money,
+    #[serde(default)]
+    pub interest: InterestRate,
+}
+
+impl Account {
+    /// Create a new account.
+    /// # Arguments
+    /// - `name`: The name of the account.
+    /// - `balance`: The current balance of the account.
+    /// # Returns
+    /// - A new account.
+    pub fn new(name: String, balance: Money) -> Self {
+        Self {
+            uuid: Uuid::new_v4(),
+            name,
+            balance,
+            interest: InterestRate::default(),
+        }
+    }
+
+    /// Returns the uuid
+    pub fn uuid(&self) -> Uuid {
+        self.uuid
+    }
+
+    /// Returns the name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the current balance
+    pub fn balance(&self) -> Money {
+        self.balance
+    }
+
+    /// Returns the interest rate (or the expected return) of the balance
+    pub fn interest(&self) -> InterestRate {
+        self.interest
+    }
+}
@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::Money;
+
+/// The percentage of a budget from which the spending is shown as a warning.
+const WARNING_PERCENT: i64 = 80;
+
+/// How much of a budget is spent.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BudgetStatus {
+    /// Less than 80% of the budget is spent.
+    Ok,
+    /// At least 80% of the budget is spent, but it's not exceeded.
+    Warning,
+    /// The budget is exceeded.
+    Exceeded,
+}
+
+/// A limit on how much can be spent in a category each month.
+#[derive(Clone, Debug, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct Budget {
+    uuid: Uuid,
+    pub category: String,
+    pub limit: Money,
+}
+
+impl Budget {
+    /// Create a new budget.
+    /// # Arguments
+    /// - `category`: The category the budget applies to.
+    /// - `limit`: The maximum amount to spend each month.
+    /// # Returns
+    /// - A new budget.
+    pub fn new(category: String, limit: Money) -> Self {
+        Self {
+            uuid: Uuid::new_v4(),
+            category,
+            limit,
+        }
+    }
+
+    /// Returns the uuid
+    pub fn uuid(&self) -> Uuid {
+        self.uuid
+    }
+
+    /// Returns the category
+    pub fn category(&self) -> &str {
+        &self.category
+    }
+
+    /// Returns the maximum amount to spend each month
+    pub fn limit(&self) -> Money {
+        self.limit
+    }
+
+    /// Returns how much of the budget the given spending uses.
+    /// # Arguments
+    /// - `spent`: The amount spent in a month.
+    /// # Returns
+    /// - The status of the budget.
+    /// # Examples
+    /// ```
+    /// use nix_bucks::{Budget, BudgetStatus, Money};
+    ///
+    /// fn main() {
+    ///     let budget = Budget::new(String::from("Food"), Money::from_cents(40000));
+    ///
+    ///     assert_eq!(budget.status(Money::from_cents(20000)), BudgetStatus::Ok);
+    ///     assert_eq!(budget.status(Money::from_cents(32000)), BudgetStatus::Warning);
+    ///     assert_eq!(budget.status(Money::from_cents(40001)), BudgetStatus::Exceeded);
+    /// }
+    /// ```
+    pub fn status(&self, spent: Money) -> BudgetStatus {
+        if spent > self.limit {
+            BudgetStatus::Exceeded
+        } else if spent > Money::ZERO && spent * 100 >= self.limit * WARNING_PERCENT {
+            BudgetStatus::Warning
+        } else {
+            BudgetStatus::Ok
+        }
+    }
+}
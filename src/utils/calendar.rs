@@ -0,0 +1,127 @@
+use std::collections::BTreeSet;
+
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use internationalization::t;
+use serde::{Deserialize, Serialize};
+
+use super::load_config;
+
+/// What to do with a payment that falls on a weekend or on a bank holiday.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum BusinessDayRule {
+    /// The date is kept as it is.
+    #[default]
+    None,
+    /// The payment is moved to the next working day.
+    Following,
+    /// The payment is moved to the previous working day.
+    Preceding,
+    /// The payment is moved to the next working day, unless it's in the next month. In that case,
+    /// it's moved to the previous working day.
+    ModifiedFollowing,
+}
+
+impl BusinessDayRule {
+    /// Returns the string representation according to the language given.
+    /// # Arguments
+    /// - `lang`: The language.
+    /// # Returns
+    /// - The string representation according to the language given.
+    pub fn to_lang_str(&self, lang: &str) -> String {
+        match self {
+            Self::None => t!("business_day.none", lang),
+            Self::Following => t!("business_day.following", lang),
+            Self::Preceding => t!("business_day.preceding", lang),
+            Self::ModifiedFollowing => t!("business_day.modified_following", lang),
+        }
+    }
+}
+
+/// A set of bank holidays. Weekends are never working days, so they don't need to be listed.
+///
+/// It's stored in the config folder as "holidays.json", which is a list of dates, e.g.
+/// `["2023-12-25", "2024-01-01"]`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct HolidayCalendar {
+    holidays: BTreeSet<NaiveDate>,
+}
+
+impl HolidayCalendar {
+    /// Creates a calendar with the given holidays.
+    /// # Arguments
+    /// - `holidays`: The bank holidays.
+    /// # Returns
+    /// - A new calendar.
+    pub fn new(holidays: impl IntoIterator<Item = NaiveDate>) -> Self {
+        Self {
+            holidays: holidays.into_iter().collect(),
+        }
+    }
+
+    /// Loads the calendar from the "holidays.json" file in the config folder. If the file does not
+    /// exist or can't be read, an empty calendar is returned.
+    pub fn load() -> Self {
+        load_config("holidays.json", "holidays")
+    }
+
+    /// Returns true if the given date is neither a weekend nor a holiday.
+    pub fn is_business_day(&self, date: NaiveDate) -> bool {
+        !matches!(date.weekday(), Weekday::Sat | Weekday::Sun) && !self.holidays.contains(&date)
+    }
+
+    /// Moves the date to a working day according to the given rule.
+    /// # Arguments
+    /// - `date`: The date.
+    /// - `rule`: The rule to apply.
+    /// # Returns
+    /// - The adjusted date.
+    /// # Examples
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use nix_bucks::{BusinessDayRule, HolidayCalendar};
+    ///
+    /// fn main() {
+    ///     let calendar = HolidayCalendar::new([NaiveDate::from_ymd_opt(2023, 12, 25).unwrap()]);
+    ///
+    ///     // Saturday the 23rd of December
+    ///     let date = NaiveDate::from_ymd_opt(2023, 12, 23).unwrap();
+    ///
+    ///     assert_eq!(
+    ///         calendar.adjust(date, BusinessDayRule::Following),
+    ///         NaiveDate::from_ymd_opt(2023, 12, 26).unwrap()
+    ///     );
+    ///     assert_eq!(
+    ///         calendar.adjust(date, BusinessDayRule::Preceding),
+    ///         NaiveDate::from_ymd_opt(2023, 12, 22).unwrap()
+    ///     );
+    /// }
+    /// ```
+    pub fn adjust(&self, date: NaiveDate, rule: BusinessDayRule) -> NaiveDate {
+        match rule {
+            BusinessDayRule::None => date,
+            BusinessDayRule::Following => self.step(date, 1),
+            BusinessDayRule::Preceding => self.step(date, -1),
+            BusinessDayRule::ModifiedFollowing => {
+                let following = self.step(date, 1);
+
+                if following.month() == date.month() {
+                    following
+                } else {
+                    self.step(date, -1)
+                }
+            }
+        }
+    }
+
+    /// Moves the date one day at a time in the given direction until it's a working day.
+    fn step(&self, date: NaiveDate, direction: i64) -> NaiveDate {
+        let mut date = date;
+
+        while !self.is_business_day(date) {
+            date += Duration::days(direction);
+        }
+
+        date
+    }
+}
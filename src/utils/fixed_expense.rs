@@ -1,16 +1,31 @@
 use chrono::{NaiveDate, Utc};
-use ordered_float::OrderedFloat;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use super::{Estimate, Labels, Money, Split, Uncertainty};
+
 /// A fixed expense is an expense that is not recurrent.
 #[derive(Clone, Debug, Serialize, Deserialize, Hash, PartialEq, Eq)]
 pub struct FixedExpense {
     uuid: Uuid,
     pub name: String,
-    pub cost: OrderedFloat<f32>,
+    pub cost: Money,
 
     pub date: NaiveDate,
+
+    #[serde(default)]
+    pub labels: Labels,
+
+    #[serde(default)]
+    pub account: Option<Uuid>,
+
+    /// How much the cost can vary, if it's uncertain.
+    #[serde(default)]
+    pub uncertainty: Option<Uncertainty>,
+
+    /// How the cost is shared with the household members.
+    #[serde(default)]
+    pub split: Split,
 }
 
 impl Default for FixedExpense {
@@ -18,8 +33,12 @@ impl Default for FixedExpense {
         Self {
             uuid: Uuid::new_v4(),
             name: String::new(),
-            cost: OrderedFloat(0.0),
+            cost: Money::ZERO,
             date: Utc::now().naive_utc().date(),
+            labels: Labels::default(),
+            account: None,
+            uncertainty: None,
+            split: Split::default(),
         }
     }
 }
@@ -35,24 +54,28 @@ impl FixedExpense {
     /// # Examples
     /// ```
     /// use chrono::{Utc, NaiveDate};
-    /// use nix_bucks::FixedExpense;
+    /// use nix_bucks::{FixedExpense, Money};
     ///
     /// pub fn main() {
     ///    let fixed_expense = FixedExpense::new(
     ///        String::from("My new fixed expense"),
-    ///        123.0,
+    ///        Money::from_cents(12300),
     ///        Utc::now().naive_utc().date()
     ///     );
     ///
     ///     println!("{:?}", fixed_expense);
     /// }
     /// ```
-    pub fn new(name: String, cost: f32, date: NaiveDate) -> Self {
+    pub fn new(name: String, cost: Money, date: NaiveDate) -> Self {
         Self {
             uuid: Uuid::new_v4(),
             name,
-            cost: OrderedFloat(cost),
+            cost,
             date,
+            labels: Labels::default(),
+            account: None,
+            uncertainty: None,
+            split: Split::default(),
         }
     }
 
@@ -62,8 +85,18 @@ impl FixedExpense {
     }
 
     /// Returns the cost.
-    pub fn cost(&self) -> f32 {
-        self.cost.0
+    pub fn cost(&self) -> Money {
+        self.cost
+    }
+
+    /// Returns the user's share of the cost, which is what the entry takes out of (or adds to)
+    /// their balance.
+    pub fn my_cost(&self) -> Money {
+        if self.split.is_shared() {
+            self.split.my_share(self.cost)
+        } else {
+            self.cost
+        }
     }
 
     /// Returns the date
@@ -71,8 +104,30 @@ impl FixedExpense {
         self.date
     }
 
+    /// Returns the category and the tags.
+    pub fn labels(&self) -> &Labels {
+        &self.labels
+    }
+
+    /// Returns the account the entry is charged to or paid into (`None` is the main account).
+    pub fn account(&self) -> Option<Uuid> {
+        self.account
+    }
+
     /// Returns the uuid.
     pub fn uuid(&self) -> Uuid {
         self.uuid
     }
+
+    /// Returns the estimated cost.
+    /// # Arguments
+    /// - `estimate`: Which amount to return.
+    /// # Returns
+    /// - The estimated cost, which is the cost if it's certain.
+    pub fn estimate(&self, estimate: Estimate) -> Money {
+        match &self.uncertainty {
+            Some(uncertainty) => uncertainty.estimate(self.cost, self.cost, estimate),
+            None => self.cost,
+        }
+    }
 }
@@ -0,0 +1,570 @@
+use chrono::{Days, Months, NaiveDate};
+use uuid::Uuid;
+
+use super::{
+    months_between, Compounding, Estimate, FixedExpense, HolidayCalendar, Installment,
+    InterestRate, Labels, Loan, Money, Split, Subscription, Transfer,
+};
+
+/// Where a change of the balance comes from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FlowKind {
+    /// A recurrent or one-off income.
+    Income,
+    /// A subscription, a one-off expense, an installment or a loan payment.
+    Cost,
+    /// The interest of a balance.
+    Interest,
+    /// Money moved from or to another account.
+    Transfer,
+}
+
+/// A change of the balance on a given date.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ForecastEntry {
+    pub date: NaiveDate,
+    /// The name of the entry the change comes from.
+    pub name: String,
+    pub kind: FlowKind,
+    /// The change, negative for the costs. For a cost shared with the household, it's only the
+    /// user's share.
+    pub amount: Money,
+    /// The part of a shared cost that the household members pay, which is not in `amount`.
+    pub members_share: Money,
+    /// The planned entry the change comes from, if any.
+    pub entry: Option<Uuid>,
+    /// The account the change is charged to or paid into (`None` is the main account).
+    pub account: Option<Uuid>,
+    /// The labels of the entry the change comes from.
+    pub labels: Labels,
+    /// The balance right after the change.
+    pub balance: Money,
+}
+
+/// The entry the changes being added come from.
+struct Source<'a> {
+    name: &'a str,
+    kind: FlowKind,
+    entry: Option<Uuid>,
+    account: Option<Uuid>,
+    labels: &'a Labels,
+}
+
+/// A day-by-day forecast of a balance: every income, cost, interest payment and transfer between
+/// two dates, with the running balance after each of them.
+///
+/// Every report is computed from it, so they all agree with each other.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Forecast {
+    opening: Money,
+    from: NaiveDate,
+    to: NaiveDate,
+    inflation: u32,
+    /// The changes, sorted by date. The running balance is worked out again whenever changes are
+    /// added.
+    entries: Vec<ForecastEntry>,
+}
+
+impl Forecast {
+    /// Creates an empty forecast.
+    /// # Arguments
+    /// - `opening`: The balance before the first day.
+    /// - `from`: The first day (included).
+    /// - `to`: The last day (included).
+    /// - `inflation`: The global annual inflation rate in basis points (hundredths of a percent).
+    ///   The prices of the recurrent entries go up with it every whole year after `from`.
+    /// # Returns
+    /// - A forecast without any change, so the balance stays at `opening`.
+    /// # Examples
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use nix_bucks::{
+    ///     Estimate, FlowKind, Forecast, HolidayCalendar, Money, Recurrence, Subscription,
+    /// };
+    ///
+    /// fn main() {
+    ///     let from = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+    ///     let to = NaiveDate::from_ymd_opt(2023, 3, 31).unwrap();
+    ///     let holidays = HolidayCalendar::default();
+    ///     let salary = Subscription::new(
+    ///         String::from("Salary"),
+    ///         Money::from_cents(200000),
+    ///         Recurrence::Month(28, 1),
+    ///     );
+    ///     let rent = Subscription::new(
+    ///         String::from("Rent"),
+    ///         Money::from_cents(80000),
+    ///         Recurrence::Month(1, 1),
+    ///     );
+    ///
+    ///     let mut forecast = Forecast::new(Money::from_cents(10000), from, to, 0);
+    ///     forecast.add_subscription(&salary, FlowKind::Income, &holidays, Estimate::Expected);
+    ///     forecast.add_subscription(&rent, FlowKind::Cost, &holidays, Estimate::Expected);
+    ///
+    ///     // The rent is paid before the first salary
+    ///     assert_eq!(forecast.entries()[0].balance, Money::from_cents(-70000));
+    ///     assert_eq!(forecast.balance_on(to), Money::from_cents(10000 + 3 * 120000));
+    /// }
+    /// ```
+    pub fn new(opening: Money, from: NaiveDate, to: NaiveDate, inflation: u32) -> Self {
+        Self {
+            opening,
+            from,
+            to,
+            inflation,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Returns the balance before the first day
+    pub fn opening(&self) -> Money {
+        self.opening
+    }
+
+    /// Returns the first day
+    pub fn from(&self) -> NaiveDate {
+        self.from
+    }
+
+    /// Returns the last day
+    pub fn to(&self) -> NaiveDate {
+        self.to
+    }
+
+    /// Returns the changes of the balance in chronological order, with the running balance. The
+    /// changes of the same day are in the order they were added.
+    pub fn entries(&self) -> &[ForecastEntry] {
+        &self.entries
+    }
+
+    /// Adds a change of the balance. It's left out if it's not between the first and the last day.
+    /// # Arguments
+    /// - `date`: The date of the change.
+    /// - `name`: The name of the entry it comes from.
+    /// - `kind`: Where it comes from.
+    /// - `amount`: The change, negative for the costs.
+    pub fn add(&mut self, date: NaiveDate, name: &str, kind: FlowKind, amount: Money) {
+        let labels = Labels::default();
+        let source = Source {
+            name,
+            kind,
+            entry: None,
+            account: None,
+            labels: &labels,
+        };
+
+        self.push(&source, date, amount, Money::ZERO);
+        self.rebalance();
+    }
+
+    /// Adds the payments of a subscription or a recurrent income, escalated by inflation.
+    /// # Arguments
+    /// - `subscription`: The subscription or the income.
+    /// - `kind`: [`FlowKind::Income`] for an income, anything else for a cost.
+    /// - `holidays`: The bank holidays.
+    /// - `estimate`: Which amount of each payment to use, if it's uncertain.
+    pub fn add_subscription(
+        &mut self,
+        subscription: &Subscription,
+        kind: FlowKind,
+        holidays: &HolidayCalendar,
+        estimate: Estimate,
+    ) {
+        let charges: Vec<(NaiveDate, Money)> = subscription
+            .escalated_charges(self.from, self.to, holidays, self.inflation, self.from)
+            .collect();
+        let source = Source {
+            name: subscription.name(),
+            kind,
+            entry: Some(subscription.uuid()),
+            account: subscription.account(),
+            labels: subscription.labels(),
+        };
+
+        for (date, cost) in charges {
+            let amount = subscription.estimate(cost, estimate);
+            self.push_split(&source, date, amount, subscription.split());
+        }
+
+        self.rebalance();
+    }
+
+    /// Adds a one-off expense or income. Only the user's share of a shared expense changes the
+    /// balance.
+    /// # Arguments
+    /// - `expense`: The expense or the income.
+    /// - `kind`: [`FlowKind::Income`] for an income, anything else for a cost.
+    /// - `estimate`: Which amount to use, if it's uncertain.
+    pub fn add_fixed(&mut self, expense: &FixedExpense, kind: FlowKind, estimate: Estimate) {
+        let source = Source {
+            name: expense.name(),
+            kind,
+            entry: Some(expense.uuid()),
+            account: expense.account(),
+            labels: expense.labels(),
+        };
+
+        self.push_split(
+            &source,
+            expense.date(),
+            expense.estimate(estimate),
+            &expense.split,
+        );
+        self.rebalance();
+    }
+
+    /// Adds the payments of an installment plan.
+    pub fn add_installment(&mut self, installment: &Installment) {
+        let source = Source {
+            name: installment.name(),
+            kind: FlowKind::Cost,
+            entry: Some(installment.uuid()),
+            account: installment.account(),
+            labels: installment.labels(),
+        };
+
+        for (date, cost) in installment.charges(self.from, self.to) {
+            self.push(&source, date, -cost, Money::ZERO);
+        }
+
+        self.rebalance();
+    }
+
+    /// Adds the payments of a loan.
+    pub fn add_loan(&mut self, loan: &Loan) {
+        let source = Source {
+            name: loan.name(),
+            kind: FlowKind::Cost,
+            entry: Some(loan.uuid()),
+            account: loan.account(),
+            labels: loan.labels(),
+        };
+
+        for (date, cost) in loan.charges(self.from, self.to) {
+            self.push(&source, date, -cost, Money::ZERO);
+        }
+
+        self.rebalance();
+    }
+
+    /// Adds the money a transfer moves into or out of an account.
+    /// # Arguments
+    /// - `transfer`: The transfer.
+    /// - `account`: The account the forecast is for (`None` is the main account).
+    pub fn add_transfer(&mut self, transfer: &Transfer, account: Option<Uuid>) {
+        let dates: Vec<NaiveDate> = transfer.occurrences(self.from, self.to).collect();
+        let labels = Labels::default();
+        let source = Source {
+            name: transfer.name(),
+            kind: FlowKind::Transfer,
+            entry: Some(transfer.uuid()),
+            account,
+            labels: &labels,
+        };
+
+        for date in dates {
+            let amount = transfer.net_between(account, date, date);
+            self.push(&source, date, amount, Money::ZERO);
+        }
+
+        self.rebalance();
+    }
+
+    /// Adds the interest an account earns, paid at the end of every period. The interest of a
+    /// period is earned by the average balance of the account during the period, so the money paid
+    /// into the account earns interest from the day it arrives, and the interest already paid
+    /// earns interest too. Negative balances earn nothing, as in [`InterestRate::accrued`].
+    ///
+    /// It must be added after the other changes of the account, since they change its balance.
+    /// # Arguments
+    /// - `account`: The account (`None` is the main account).
+    /// - `name`: The name of the account.
+    /// - `balance`: The balance of the account before the first day.
+    /// - `interest`: The interest rate.
+    pub fn add_interest(
+        &mut self,
+        account: Option<Uuid>,
+        name: &str,
+        balance: Money,
+        interest: InterestRate,
+    ) {
+        if interest.annual_rate == 0 {
+            return;
+        }
+
+        let changes: Vec<(NaiveDate, Money)> = self
+            .entries
+            .iter()
+            .filter(|entry| entry.account == account)
+            .map(|entry| (entry.date, entry.amount))
+            .collect();
+        let labels = Labels::default();
+        let source = Source {
+            name,
+            kind: FlowKind::Interest,
+            entry: None,
+            account,
+            labels: &labels,
+        };
+        let rate = interest.period_rate();
+
+        // The balance without the interest, and the interest earned so far before rounding it, so
+        // that a balance that doesn't change earns exactly what `InterestRate::accrued` says
+        let mut principal = balance.cents() as f64;
+        let mut earned = 0.0;
+        let mut paid = Money::ZERO;
+        let mut next_change = 0;
+        let mut day = self.from;
+
+        for period in 1.. {
+            let date = match interest.compounding {
+                Compounding::Daily => self.from.checked_add_days(Days::new(period)),
+                Compounding::Monthly => self.from.checked_add_months(Months::new(period as u32)),
+                Compounding::Quarterly => {
+                    self.from.checked_add_months(Months::new(3 * period as u32))
+                }
+                Compounding::Yearly => self
+                    .from
+                    .checked_add_months(Months::new(12 * period as u32)),
+            };
+
+            let Some(date) = date.filter(|date| *date <= self.to) else {
+                break;
+            };
+
+            // The sum of the balances at the end of every day of the period
+            let mut total = 0.0;
+            let days = (date - day).num_days();
+
+            while day < date {
+                while let Some((_, amount)) = changes
+                    .get(next_change)
+                    .filter(|(changed, _)| *changed <= day)
+                {
+                    principal += amount.cents() as f64;
+                    next_change += 1;
+                }
+
+                total += (principal + earned).max(0.0);
+                day = day.succ_opt().unwrap_or(date);
+            }
+
+            earned += total / days as f64 * rate;
+
+            let accrued = Money::from_cents(earned.round() as i64);
+            self.push(&source, date, accrued - paid, Money::ZERO);
+            paid = accrued;
+        }
+
+        self.rebalance();
+    }
+
+    /// Returns the balance at the end of the given day.
+    pub fn balance_on(&self, date: NaiveDate) -> Money {
+        let index = self.entries.partition_point(|entry| entry.date <= date);
+
+        match index {
+            0 => self.opening,
+            i => self.entries[i - 1].balance,
+        }
+    }
+
+    /// Returns the sum of the changes of the given kind until the given day (included). It's
+    /// negative for the costs.
+    pub fn total(&self, kind: FlowKind, to: NaiveDate) -> Money {
+        self.entries
+            .iter()
+            .take_while(|entry| entry.date <= to)
+            .filter(|entry| entry.kind == kind)
+            .map(|entry| entry.amount)
+            .sum()
+    }
+
+    /// Returns the sum of the changes between the two given days (both included) that satisfy the
+    /// condition. It's negative for the costs.
+    pub fn total_between(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+        condition: impl Fn(&ForecastEntry) -> bool,
+    ) -> Money {
+        let first = self.entries.partition_point(|entry| entry.date < from);
+
+        self.entries[first..]
+            .iter()
+            .take_while(|entry| entry.date <= to)
+            .filter(|entry| condition(entry))
+            .map(|entry| entry.amount)
+            .sum()
+    }
+
+    /// Returns the part of the shared costs until the given day (included) that the household
+    /// members pay.
+    pub fn members_share(&self, to: NaiveDate) -> Money {
+        self.entries
+            .iter()
+            .take_while(|entry| entry.date <= to)
+            .map(|entry| entry.members_share)
+            .sum()
+    }
+
+    /// Returns the balance at the end of every day with changes, in chronological order.
+    pub fn daily_balances(&self) -> Vec<(NaiveDate, Money)> {
+        let mut days: Vec<(NaiveDate, Money)> = Vec::new();
+
+        for entry in &self.entries {
+            match days.last_mut() {
+                Some((date, balance)) if *date == entry.date => *balance = entry.balance,
+                _ => days.push((entry.date, entry.balance)),
+            }
+        }
+
+        days
+    }
+
+    /// Returns the lowest balance at the end of a day, and the first day it's reached on. The
+    /// balances within a day are left out, since the order of the changes of a day is unknown.
+    /// # Returns
+    /// - The day and the balance, or the first day and the opening balance if it never goes lower.
+    /// # Examples
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use nix_bucks::{FlowKind, Forecast, Money};
+    ///
+    /// fn main() {
+    ///     let from = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+    ///     let rent = NaiveDate::from_ymd_opt(2023, 1, 5).unwrap();
+    ///     let payday = NaiveDate::from_ymd_opt(2023, 1, 28).unwrap();
+    ///     let to = NaiveDate::from_ymd_opt(2023, 1, 31).unwrap();
+    ///
+    ///     let mut forecast = Forecast::new(Money::from_cents(50000), from, to, 0);
+    ///     forecast.add(rent, "Rent", FlowKind::Cost, Money::from_cents(-80000));
+    ///     forecast.add(payday, "Salary", FlowKind::Income, Money::from_cents(200000));
+    ///
+    ///     assert_eq!(forecast.lowest(), (rent, Money::from_cents(-30000)));
+    ///     assert_eq!(forecast.first_negative(), Some(rent));
+    /// }
+    /// ```
+    pub fn lowest(&self) -> (NaiveDate, Money) {
+        self.daily_balances()
+            .into_iter()
+            .fold((self.from, self.opening), |lowest, day| {
+                if day.1 < lowest.1 {
+                    day
+                } else {
+                    lowest
+                }
+            })
+    }
+
+    /// Returns the first day that ends with a negative balance, if any.
+    pub fn first_negative(&self) -> Option<NaiveDate> {
+        if self.opening.is_negative() {
+            return Some(self.from);
+        }
+
+        self.daily_balances()
+            .into_iter()
+            .find(|(_, balance)| balance.is_negative())
+            .map(|(date, _)| date)
+    }
+
+    /// Returns the same forecast without the changes of the given kind.
+    pub fn without(&self, kind: FlowKind) -> Forecast {
+        self.filtered(self.opening, self.to, |entry| entry.kind != kind)
+    }
+
+    /// Returns a part of the forecast: the changes until the given day that satisfy the
+    /// condition, starting from another balance. It's used to get the forecast of a single
+    /// account, or of the entries with some labels, without building it again.
+    /// # Arguments
+    /// - `opening`: The balance before the first day.
+    /// - `to`: The last day, which can't be after the last day of this forecast.
+    /// - `condition`: Returns true for the changes to keep.
+    pub fn filtered(
+        &self,
+        opening: Money,
+        to: NaiveDate,
+        condition: impl Fn(&ForecastEntry) -> bool,
+    ) -> Forecast {
+        let mut forecast = Forecast {
+            opening,
+            from: self.from,
+            to: to.min(self.to),
+            inflation: self.inflation,
+            entries: self
+                .entries
+                .iter()
+                .take_while(|entry| entry.date <= to)
+                .filter(|entry| condition(entry))
+                .cloned()
+                .collect(),
+        };
+        forecast.rebalance();
+
+        forecast
+    }
+
+    /// Returns how many whole months the balance lasts if all the income stops, i.e. the months
+    /// from the first day until the first day with a negative balance without any income.
+    /// # Returns
+    /// - The number of months, or `None` if the balance lasts until the last day.
+    pub fn runway(&self) -> Option<u32> {
+        self.without(FlowKind::Income)
+            .first_negative()
+            .map(|date| months_between(self.from, date))
+    }
+
+    /// Adds a change and the part of it the household members pay, without working out the
+    /// running balance. It's left out if it's not between the first and the last day.
+    fn push(&mut self, source: &Source, date: NaiveDate, amount: Money, members_share: Money) {
+        if date < self.from
+            || self.to < date
+            || (amount == Money::ZERO && members_share == Money::ZERO)
+        {
+            return;
+        }
+
+        self.entries.push(ForecastEntry {
+            date,
+            name: source.name.to_string(),
+            kind: source.kind,
+            amount,
+            members_share,
+            entry: source.entry,
+            account: source.account,
+            labels: source.labels.clone(),
+            balance: Money::ZERO,
+        });
+    }
+
+    /// Adds the user's share of an amount, positive for the incomes and negative for everything
+    /// else, without working out the running balance.
+    fn push_split(&mut self, source: &Source, date: NaiveDate, amount: Money, split: &Split) {
+        let mine = if split.is_shared() {
+            split.my_share(amount)
+        } else {
+            amount
+        };
+        let signed = if source.kind == FlowKind::Income {
+            mine
+        } else {
+            -mine
+        };
+
+        self.push(source, date, signed, amount - mine);
+    }
+
+    /// Sorts the changes by date and works out the running balance. The sort is stable, so the
+    /// changes of the same day keep the order they were added in.
+    fn rebalance(&mut self) {
+        self.entries.sort_by_key(|entry| entry.date);
+
+        let mut balance = self.opening;
+
+        for entry in &mut self.entries {
+            balance += entry.amount;
+            entry.balance = balance;
+        }
+    }
+}
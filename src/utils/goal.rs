@@ -0,0 +1,231 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{months_between, Money};
+
+/// A savings goal: an amount of money to have saved in an account by a date.
+#[derive(Clone, Debug, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct Goal {
+    uuid: Uuid,
+    pub name: String,
+    pub target: Money,
+    pub date: NaiveDate,
+    #[serde(default)]
+    pub account: Option<Uuid>,
+}
+
+impl Goal {
+    /// Create a new savings goal.
+    /// # Arguments
+    /// - `name`: The name of the goal.
+    /// - `target`: The amount to save.
+    /// - `date`: The date by which the amount must be saved.
+    /// - `account`: The account the money is saved in (`None` is the main account).
+    /// # Returns
+    /// - A new savings goal.
+    pub fn new(name: String, target: Money, date: NaiveDate, account: Option<Uuid>) -> Self {
+        Self {
+            uuid: Uuid::new_v4(),
+            name,
+            target,
+            date,
+            account,
+        }
+    }
+
+    /// Returns the uuid
+    pub fn uuid(&self) -> Uuid {
+        self.uuid
+    }
+
+    /// Returns the name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the amount to save
+    pub fn target(&self) -> Money {
+        self.target
+    }
+
+    /// Returns the date by which the amount must be saved
+    pub fn date(&self) -> NaiveDate {
+        self.date
+    }
+
+    /// Returns the account the money is saved in (`None` is the main account)
+    pub fn account(&self) -> Option<Uuid> {
+        self.account
+    }
+
+    /// Returns the amount of whole months left from the given date until the target date.
+    /// # Arguments
+    /// - `today`: The current date.
+    /// # Returns
+    /// - The amount of months, or zero if the target date has passed.
+    pub fn months_left(&self, today: NaiveDate) -> u32 {
+        months_between(today, self.date)
+    }
+
+    /// Returns how much must be saved each month to reach the goal on time. When there are no
+    /// months left, the whole remaining amount is needed at once.
+    /// # Arguments
+    /// - `saved`: The amount already saved.
+    /// - `today`: The current date.
+    /// # Returns
+    /// - The monthly contribution, rounded up to the cent.
+    /// # Examples
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use nix_bucks::{Goal, Money};
+    ///
+    /// fn main() {
+    ///     let holiday = Goal::new(
+    ///         String::from("Holiday"),
+    ///         Money::from_cents(100000),
+    ///         NaiveDate::from_ymd_opt(2023, 8, 1).unwrap(),
+    ///         None,
+    ///     );
+    ///     let today = NaiveDate::from_ymd_opt(2023, 5, 1).unwrap();
+    ///
+    ///     // 900€ left in 3 months
+    ///     assert_eq!(
+    ///         holiday.required_monthly(Money::from_cents(10000), today),
+    ///         Money::from_cents(30000)
+    ///     );
+    /// }
+    /// ```
+    pub fn required_monthly(&self, saved: Money, today: NaiveDate) -> Money {
+        let remaining = self.target - saved;
+
+        if remaining <= Money::ZERO {
+            return Money::ZERO;
+        }
+
+        match self.months_left(today) as i64 {
+            0 => remaining,
+            months => Money::from_cents((remaining.cents() + months - 1) / months),
+        }
+    }
+
+    /// Returns the fraction of the goal already saved, between 0 and 1.
+    /// # Arguments
+    /// - `saved`: The amount already saved.
+    pub fn progress(&self, saved: Money) -> f32 {
+        if self.target <= Money::ZERO {
+            return 1.0;
+        }
+
+        (saved.cents() as f32 / self.target.cents() as f32).clamp(0.0, 1.0)
+    }
+
+    /// Returns true if saving the given amount each month reaches the goal by the target date.
+    /// # Arguments
+    /// - `saved`: The amount already saved.
+    /// - `monthly`: The amount saved each month.
+    /// - `today`: The current date.
+    pub fn is_on_track(&self, saved: Money, monthly: Money, today: NaiveDate) -> bool {
+        saved + monthly * self.months_left(today) as i64 >= self.target
+    }
+}
+
+/// Splits the balance of each account between the goals saved in it, so that the same money isn't
+/// counted twice. The goals with the earliest target date are funded first, each one taking what
+/// it still needs, and the last goal of an account gets whatever is left.
+/// # Arguments
+/// - `goals`: The goals.
+/// - `balance`: Returns the current balance of an account (`None` is the main account).
+/// # Returns
+/// - The amount saved for each goal, by uuid.
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use nix_bucks::{allocate_savings, Goal, Money};
+///
+/// fn main() {
+///     let holiday = Goal::new(
+///         String::from("Holiday"),
+///         Money::from_cents(100000),
+///         NaiveDate::from_ymd_opt(2023, 8, 1).unwrap(),
+///         None,
+///     );
+///     let car = Goal::new(
+///         String::from("Car"),
+///         Money::from_cents(600000),
+///         NaiveDate::from_ymd_opt(2024, 5, 1).unwrap(),
+///         None,
+///     );
+///
+///     let saved = allocate_savings(&[car.clone(), holiday.clone()], |_| Money::from_cents(150000));
+///
+///     assert_eq!(saved[&holiday.uuid()], Money::from_cents(100000));
+///     assert_eq!(saved[&car.uuid()], Money::from_cents(50000));
+/// }
+/// ```
+pub fn allocate_savings(
+    goals: &[Goal],
+    balance: impl Fn(Option<Uuid>) -> Money,
+) -> HashMap<Uuid, Money> {
+    let mut goals: Vec<&Goal> = goals.iter().collect();
+    goals.sort_by(|a, b| a.date.cmp(&b.date).then_with(|| a.name.cmp(&b.name)));
+
+    let mut left: HashMap<Option<Uuid>, Money> = HashMap::new();
+    let mut saved = HashMap::new();
+
+    for (i, goal) in goals.iter().enumerate() {
+        let available = left
+            .entry(goal.account)
+            .or_insert_with(|| balance(goal.account).max(Money::ZERO));
+        let is_last = goals[i + 1..].iter().all(|g| g.account != goal.account);
+        let amount = if is_last {
+            *available
+        } else {
+            goal.target.max(Money::ZERO).min(*available)
+        };
+
+        *available -= amount;
+        saved.insert(goal.uuid, amount);
+    }
+
+    saved
+}
+
+/// Splits the amount saved each month between the goals, so that the same money isn't counted
+/// twice. As with [`allocate_savings`], the goals with the earliest target date get what they need
+/// each month first, and the last goal gets whatever is left.
+/// # Arguments
+/// - `goals`: The goals.
+/// - `saved`: The amount already saved for each goal, by uuid.
+/// - `monthly`: The amount saved each month.
+/// - `today`: The current date.
+/// # Returns
+/// - The monthly contribution to each goal, by uuid.
+pub fn allocate_contributions(
+    goals: &[Goal],
+    saved: &HashMap<Uuid, Money>,
+    monthly: Money,
+    today: NaiveDate,
+) -> HashMap<Uuid, Money> {
+    let mut goals: Vec<&Goal> = goals.iter().collect();
+    goals.sort_by(|a, b| a.date.cmp(&b.date).then_with(|| a.name.cmp(&b.name)));
+
+    let mut left = monthly.max(Money::ZERO);
+    let mut contributions = HashMap::new();
+
+    for (i, goal) in goals.iter().enumerate() {
+        let amount = if i + 1 == goals.len() {
+            left
+        } else {
+            let saved = saved.get(&goal.uuid).copied().unwrap_or_default();
+            goal.required_monthly(saved, today).min(left)
+        };
+
+        left -= amount;
+        contributions.insert(goal.uuid, amount);
+    }
+
+    contributions
+}
@@ -0,0 +1,221 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::Money;
+
+/// Someone the expenses are shared with, e.g. a partner or a flatmate.
+#[derive(Clone, Debug, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct Member {
+    uuid: Uuid,
+    pub name: String,
+}
+
+impl Member {
+    /// Create a new household member.
+    /// # Arguments
+    /// - `name`: The name of the member.
+    /// # Returns
+    /// - A new member.
+    pub fn new(name: String) -> Self {
+        Self {
+            uuid: Uuid::new_v4(),
+            name,
+        }
+    }
+
+    /// Returns the uuid
+    pub fn uuid(&self) -> Uuid {
+        self.uuid
+    }
+
+    /// Returns the name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// How much of each payment a member pays.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub enum ShareRule {
+    /// A part of the payment, in basis points (hundredths of a percent), so 50% is 5000.
+    Percentage(u32),
+    /// A fixed amount of each payment.
+    Fixed(Money),
+}
+
+impl ShareRule {
+    /// Returns the share of a payment, which is never above the payment itself.
+    /// # Arguments
+    /// - `cost`: The amount of the payment.
+    pub fn share(&self, cost: Money) -> Money {
+        match self {
+            ShareRule::Percentage(rate) => cost.mul_ratio(*rate as i64, 10000),
+            ShareRule::Fixed(amount) => (*amount).min(cost),
+        }
+    }
+}
+
+/// How an entry is split between the user and the household members.
+///
+/// The user pays whatever the members don't. The default split is an entry the user pays alone.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct Split {
+    /// Who pays the entry in the first place (`None` is the user). The others owe them their share.
+    #[serde(default)]
+    pub paid_by: Option<Uuid>,
+    /// The share of each member.
+    #[serde(default)]
+    pub shares: Vec<(Uuid, ShareRule)>,
+}
+
+impl Split {
+    /// Returns true if the entry is split with any member.
+    pub fn is_shared(&self) -> bool {
+        !self.shares.is_empty()
+    }
+
+    /// Returns true if any member takes part in the entry, either by paying it or by having a
+    /// share of it.
+    pub fn involves_members(&self) -> bool {
+        self.is_shared() || self.paid_by.is_some()
+    }
+
+    /// Returns the share of each person of a payment (`None` is the user). The shares of the
+    /// members are taken in order, and none of them can take more than what's left.
+    /// # Arguments
+    /// - `cost`: The amount of the payment.
+    /// # Returns
+    /// - The share of each member, followed by the share of the user.
+    pub fn shares(&self, cost: Money) -> Vec<(Option<Uuid>, Money)> {
+        let mut left = cost.max(Money::ZERO);
+        let mut shares = Vec::new();
+
+        for (member, rule) in &self.shares {
+            let share = rule.share(cost).min(left);
+            left -= share;
+            shares.push((Some(*member), share));
+        }
+
+        shares.push((None, left));
+        shares
+    }
+
+    /// Returns the user's share of a payment.
+    /// # Arguments
+    /// - `cost`: The amount of the payment.
+    pub fn my_share(&self, cost: Money) -> Money {
+        self.shares(cost)
+            .last()
+            .map_or(Money::ZERO, |(_, share)| *share)
+    }
+
+    /// Returns what each person owes to whoever paid a payment.
+    /// # Arguments
+    /// - `cost`: The amount of the payment.
+    /// # Returns
+    /// - A list of debts, as (debtor, creditor, amount). `None` is the user.
+    pub fn debts(&self, cost: Money) -> Vec<(Option<Uuid>, Option<Uuid>, Money)> {
+        self.shares(cost)
+            .into_iter()
+            .filter(|(person, share)| *person != self.paid_by && *share > Money::ZERO)
+            .map(|(person, share)| (person, self.paid_by, share))
+            .collect()
+    }
+
+    /// Removes a member from the split. Their share goes back to the user, and so does the payment
+    /// if they made it.
+    pub fn remove_member(&mut self, member: Uuid) {
+        self.shares.retain(|(uuid, _)| *uuid != member);
+
+        if self.paid_by == Some(member) {
+            self.paid_by = None;
+        }
+    }
+}
+
+/// A payment that settles the shared expenses.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Settlement {
+    /// Who pays (`None` is the user).
+    pub from: Option<Uuid>,
+    /// Who gets paid (`None` is the user).
+    pub to: Option<Uuid>,
+    pub amount: Money,
+}
+
+/// Works out who owes whom from a list of debts. The debts between the same people cancel out,
+/// and the biggest debtor pays the biggest creditor first, so there are as few payments as
+/// possible.
+/// # Arguments
+/// - `debts`: The debts, as (debtor, creditor, amount). `None` is the user.
+/// # Returns
+/// - The payments that settle every debt.
+/// # Examples
+/// ```
+/// use nix_bucks::{settle, Member, Money};
+///
+/// fn main() {
+///     let partner = Member::new(String::from("Partner"));
+///     let debts = [
+///         (Some(partner.uuid()), None, Money::from_cents(50000)),
+///         (None, Some(partner.uuid()), Money::from_cents(20000)),
+///     ];
+///
+///     let settlements = settle(debts);
+///     assert_eq!(settlements.len(), 1);
+///     assert_eq!(settlements[0].from, Some(partner.uuid()));
+///     assert_eq!(settlements[0].amount, Money::from_cents(30000));
+/// }
+/// ```
+pub fn settle(
+    debts: impl IntoIterator<Item = (Option<Uuid>, Option<Uuid>, Money)>,
+) -> Vec<Settlement> {
+    let mut balances: HashMap<Option<Uuid>, Money> = HashMap::new();
+
+    for (debtor, creditor, amount) in debts {
+        *balances.entry(debtor).or_insert(Money::ZERO) -= amount;
+        *balances.entry(creditor).or_insert(Money::ZERO) += amount;
+    }
+
+    // Sorted so that the result doesn't depend on the order of the map
+    let mut debtors: Vec<(Option<Uuid>, Money)> = balances
+        .iter()
+        .filter(|(_, balance)| balance.is_negative())
+        .map(|(person, balance)| (*person, Money::ZERO - *balance))
+        .collect();
+    let mut creditors: Vec<(Option<Uuid>, Money)> = balances
+        .iter()
+        .filter(|(_, balance)| **balance > Money::ZERO)
+        .map(|(person, balance)| (*person, *balance))
+        .collect();
+    debtors.sort_by_key(|(person, amount)| (std::cmp::Reverse(*amount), *person));
+    creditors.sort_by_key(|(person, amount)| (std::cmp::Reverse(*amount), *person));
+
+    let mut settlements = Vec::new();
+    let (mut d, mut c) = (0, 0);
+
+    while d < debtors.len() && c < creditors.len() {
+        let amount = debtors[d].1.min(creditors[c].1);
+
+        settlements.push(Settlement {
+            from: debtors[d].0,
+            to: creditors[c].0,
+            amount,
+        });
+
+        debtors[d].1 -= amount;
+        creditors[c].1 -= amount;
+
+        if debtors[d].1 <= Money::ZERO {
+            d += 1;
+        }
+
+        if creditors[c].1 <= Money::ZERO {
+            c += 1;
+        }
+    }
+
+    settlements
+}
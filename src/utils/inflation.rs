@@ -0,0 +1,49 @@
+use chrono::NaiveDate;
+
+use super::{months_between, Money};
+
+/// Returns the factor prices grow by between the two given dates. Prices go up once every whole
+/// year, like a yearly salary review or a yearly price update.
+fn growth(annual_rate: u32, from: NaiveDate, to: NaiveDate) -> f64 {
+    let years = (months_between(from, to) / 12) as i32;
+
+    (1.0 + annual_rate as f64 / 10000.0).powi(years)
+}
+
+/// Escalates an amount with inflation: turns an amount in the prices of a date into the prices of
+/// a later date.
+/// # Arguments
+/// - `amount`: The amount, in the prices of `from`.
+/// - `annual_rate`: The annual inflation rate in basis points (hundredths of a percent).
+/// - `from`: The date of the prices of the amount.
+/// - `to`: The date of the new prices.
+/// # Returns
+/// - The amount in the prices of `to`.
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use nix_bucks::{escalate, Money};
+///
+/// fn main() {
+///     let today = NaiveDate::from_ymd_opt(2023, 5, 3).unwrap();
+///     let next_year = NaiveDate::from_ymd_opt(2024, 5, 3).unwrap();
+///
+///     assert_eq!(escalate(Money::from_cents(1000), 300, today, next_year), Money::from_cents(1030));
+/// }
+/// ```
+pub fn escalate(amount: Money, annual_rate: u32, from: NaiveDate, to: NaiveDate) -> Money {
+    amount.mul_f64(growth(annual_rate, from, to))
+}
+
+/// Returns the real value of an amount: turns an amount in the prices of a date into the prices of
+/// an earlier date (e.g. today's money).
+/// # Arguments
+/// - `amount`: The amount, in the prices of `to`.
+/// - `annual_rate`: The annual inflation rate in basis points (hundredths of a percent).
+/// - `from`: The date of the new prices.
+/// - `to`: The date of the prices of the amount.
+/// # Returns
+/// - The amount in the prices of `from`.
+pub fn to_real(amount: Money, annual_rate: u32, from: NaiveDate, to: NaiveDate) -> Money {
+    amount.mul_f64(1.0 / growth(annual_rate, from, to))
+}
@@ -0,0 +1,225 @@
+use chrono::{Datelike, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{Labels, Money, Recurrence};
+
+/// A temporary installment plan is an installment plan that is not yet saved.
+/// It's used to create a new installment plan.
+#[derive(Clone)]
+pub struct TmpInstallment {
+    pub name: String,
+    pub total: f64,
+    pub payments: u32,
+    pub first: NaiveDate,
+    pub months: u8,
+    pub category: Option<String>,
+    pub tags: String,
+    pub account: Option<Uuid>,
+}
+
+impl Default for TmpInstallment {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            total: 0.0,
+            payments: 12,
+            first: Utc::now().naive_utc().date(),
+            months: 1,
+            category: None,
+            tags: String::new(),
+            account: None,
+        }
+    }
+}
+
+impl From<TmpInstallment> for Installment {
+    fn from(val: TmpInstallment) -> Self {
+        let mut installment = Installment::new(
+            val.name,
+            Money::from_f64(val.total),
+            val.payments,
+            val.first,
+            Recurrence::Month(val.first.day() as u8, val.months),
+        );
+
+        installment.set_labels(Labels::new(val.category, &val.tags));
+        installment.set_account(val.account);
+
+        installment
+    }
+}
+
+/// An installment plan is a purchase paid in a fixed amount of payments.
+#[derive(Clone, Debug, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct Installment {
+    uuid: Uuid,
+    name: String,
+    total: Money,
+    payments: u32,
+    first: NaiveDate,
+    recurrence: Recurrence,
+    #[serde(default)]
+    labels: Labels,
+    #[serde(default)]
+    account: Option<Uuid>,
+}
+
+impl Installment {
+    /// Create a new installment plan.
+    /// # Arguments
+    /// - `name`: The name of the purchase.
+    /// - `total`: The total cost of the purchase.
+    /// - `payments`: The amount of payments. It must not be zero.
+    /// - `first`: The date of the first payment.
+    /// - `recurrence`: The recurrence of the rest of the payments, counted from the first one.
+    /// # Returns
+    /// - A new installment plan.
+    /// # Examples
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use nix_bucks::{Installment, Money, Recurrence};
+    ///
+    /// pub fn main() {
+    ///    let laptop = Installment::new(
+    ///        String::from("Laptop"),
+    ///        Money::from_cents(120000),
+    ///        12,
+    ///        NaiveDate::from_ymd_opt(2023, 5, 3).unwrap(),
+    ///        Recurrence::Month(3, 1),
+    ///    );
+    ///
+    ///    assert_eq!(laptop.schedule()[11].1, Money::from_cents(10000));
+    /// }
+    /// ```
+    pub fn new(
+        name: String,
+        total: Money,
+        payments: u32,
+        first: NaiveDate,
+        recurrence: Recurrence,
+    ) -> Self {
+        Self {
+            uuid: Uuid::new_v4(),
+            name,
+            total,
+            payments: payments.max(1),
+            first,
+            recurrence,
+            labels: Labels::default(),
+            account: None,
+        }
+    }
+
+    /// Returns the uuid
+    pub fn uuid(&self) -> Uuid {
+        self.uuid
+    }
+
+    /// Returns the name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the total cost
+    pub fn total(&self) -> Money {
+        self.total
+    }
+
+    /// Returns the amount of payments
+    pub fn payments(&self) -> u32 {
+        self.payments
+    }
+
+    /// Returns the date of the first payment
+    pub fn first(&self) -> NaiveDate {
+        self.first
+    }
+
+    /// Returns the recurrence of the payments
+    pub fn recurrence(&self) -> Recurrence {
+        self.recurrence
+    }
+
+    /// Returns the category and the tags
+    pub fn labels(&self) -> &Labels {
+        &self.labels
+    }
+
+    /// Sets the category and the tags.
+    /// # Arguments
+    /// - `labels`: The new labels.
+    pub fn set_labels(&mut self, labels: Labels) {
+        self.labels = labels;
+    }
+
+    /// Returns the account the entry is charged to or paid into (`None` is the main account)
+    pub fn account(&self) -> Option<Uuid> {
+        self.account
+    }
+
+    /// Sets the account the entry is charged to or paid into.
+    /// # Arguments
+    /// - `account`: The account (`None` is the main account).
+    pub fn set_account(&mut self, account: Option<Uuid>) {
+        self.account = account;
+    }
+
+    /// Returns every payment of the plan. The total is split in equal parts, and the cents that
+    /// can't be split are added to the first payments.
+    /// # Returns
+    /// - The dates and the amounts of the payments, in chronological order.
+    pub fn schedule(&self) -> Vec<(NaiveDate, Money)> {
+        let dates = std::iter::once(self.first).chain(
+            self.recurrence
+                .occurrences(self.first, NaiveDate::MAX)
+                .filter(|date| *date > self.first),
+        );
+
+        dates.zip(self.total.split(self.payments)).collect()
+    }
+
+    /// Returns the payments between the two given dates (both included).
+    /// # Arguments
+    /// - `from`: The starting date.
+    /// - `to`: The target date.
+    /// # Returns
+    /// - The dates and the amounts of the payments, in chronological order.
+    pub fn charges(&self, from: NaiveDate, to: NaiveDate) -> Vec<(NaiveDate, Money)> {
+        self.schedule()
+            .into_iter()
+            .filter(|(date, _)| from <= *date && *date <= to)
+            .collect()
+    }
+
+    /// Calculates the cost from today until the given date.
+    /// # Arguments
+    /// - `to`: The date until the cost should be calculated.
+    /// # Returns
+    /// - The cost from today until the given date.
+    pub fn cost_until(&self, to: NaiveDate) -> Money {
+        self.charges(Utc::now().naive_utc().date(), to)
+            .into_iter()
+            .map(|(_, cost)| cost)
+            .sum()
+    }
+
+    /// Returns the amount of payments made up to the given date (included).
+    pub fn paid_payments(&self, date: NaiveDate) -> u32 {
+        self.schedule().iter().filter(|(d, _)| *d <= date).count() as u32
+    }
+
+    /// Returns the amount left to pay after the given date (included).
+    pub fn remaining(&self, date: NaiveDate) -> Money {
+        self.schedule()
+            .into_iter()
+            .filter(|(d, _)| *d > date)
+            .map(|(_, cost)| cost)
+            .sum()
+    }
+
+    /// Returns true if every payment has been made by the given date (included).
+    pub fn is_finished(&self, date: NaiveDate) -> bool {
+        self.paid_payments(date) >= self.payments
+    }
+}
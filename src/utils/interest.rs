@@ -0,0 +1,125 @@
+use chrono::NaiveDate;
+use internationalization::t;
+use serde::{Deserialize, Serialize};
+
+use super::{months_between, Money};
+
+/// How often the interest is paid and added to the balance.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub enum Compounding {
+    Daily,
+    #[default]
+    Monthly,
+    Quarterly,
+    Yearly,
+}
+
+impl Compounding {
+    /// Every compounding frequency, in the order they are shown.
+    pub const ALL: [Compounding; 4] = [
+        Compounding::Daily,
+        Compounding::Monthly,
+        Compounding::Quarterly,
+        Compounding::Yearly,
+    ];
+
+    /// Returns how many times a year the interest is paid.
+    pub fn periods_per_year(&self) -> u32 {
+        match self {
+            Compounding::Daily => 365,
+            Compounding::Monthly => 12,
+            Compounding::Quarterly => 4,
+            Compounding::Yearly => 1,
+        }
+    }
+
+    /// Returns how many times the interest is paid between the two given dates. Only whole periods
+    /// count, since the interest of a period is paid at its end.
+    pub fn periods_between(&self, from: NaiveDate, to: NaiveDate) -> u32 {
+        match self {
+            Compounding::Daily => (to - from).num_days().max(0) as u32,
+            Compounding::Monthly => months_between(from, to),
+            Compounding::Quarterly => months_between(from, to) / 3,
+            Compounding::Yearly => months_between(from, to) / 12,
+        }
+    }
+}
+
+/// The interest (or the expected return) of a balance.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct InterestRate {
+    /// The annual rate in basis points (hundredths of a percent), so 3.5% is 350.
+    pub annual_rate: u32,
+    pub compounding: Compounding,
+}
+
+impl InterestRate {
+    /// Create a new interest rate.
+    /// # Arguments
+    /// - `annual_rate`: The annual rate in basis points (hundredths of a percent).
+    /// - `compounding`: How often the interest is paid.
+    /// # Returns
+    /// - A new interest rate.
+    pub fn new(annual_rate: u32, compounding: Compounding) -> Self {
+        Self {
+            annual_rate,
+            compounding,
+        }
+    }
+
+    /// Returns the rate paid on every period, as a fraction.
+    pub fn period_rate(&self) -> f64 {
+        self.annual_rate as f64 / 10000.0 / self.compounding.periods_per_year() as f64
+    }
+
+    /// Returns the interest earned by a balance between the two given dates, compounded on every
+    /// whole period. Negative balances earn nothing.
+    /// # Arguments
+    /// - `balance`: The balance, which is left untouched (apart from the interest).
+    /// - `from`: The starting date.
+    /// - `to`: The target date.
+    /// # Returns
+    /// - The interest earned.
+    /// # Examples
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use nix_bucks::{Compounding, InterestRate, Money};
+    ///
+    /// fn main() {
+    ///     let from = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+    ///     let to = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    ///
+    ///     // 12% a year paid once is 12%, paid every month is 12.68%
+    ///     let yearly = InterestRate::new(1200, Compounding::Yearly);
+    ///     let monthly = InterestRate::new(1200, Compounding::Monthly);
+    ///
+    ///     assert_eq!(yearly.accrued(Money::from_cents(100000), from, to), Money::from_cents(12000));
+    ///     assert_eq!(monthly.accrued(Money::from_cents(100000), from, to), Money::from_cents(12683));
+    /// }
+    /// ```
+    pub fn accrued(&self, balance: Money, from: NaiveDate, to: NaiveDate) -> Money {
+        if self.annual_rate == 0 || balance <= Money::ZERO {
+            return Money::ZERO;
+        }
+
+        let rate = self.period_rate();
+        let periods = self.compounding.periods_between(from, to) as i32;
+
+        balance.mul_f64((1.0 + rate).powi(periods) - 1.0)
+    }
+}
+
+/// Returns the name of the compounding frequency according to the language given.
+/// # Arguments
+/// - `compounding`: The compounding frequency.
+/// - `lang`: The language.
+/// # Returns
+/// - The name of the compounding frequency according to the language given.
+pub fn compounding_to_lang_str(compounding: Compounding, lang: &str) -> String {
+    match compounding {
+        Compounding::Daily => t!("compounding.daily", lang),
+        Compounding::Monthly => t!("compounding.monthly", lang),
+        Compounding::Quarterly => t!("compounding.quarterly", lang),
+        Compounding::Yearly => t!("compounding.yearly", lang),
+    }
+}
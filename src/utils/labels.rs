@@ -0,0 +1,88 @@
+use std::collections::BTreeSet;
+
+use serde::{Deserialize, Serialize};
+
+/// The categories every new config starts with. The user can add and remove categories later.
+const DEFAULT_CATEGORIES: [&str; 8] = [
+    "Housing",
+    "Food",
+    "Transport",
+    "Utilities",
+    "Health",
+    "Entertainment",
+    "Salary",
+    "Other",
+];
+
+/// Returns the default set of categories.
+pub fn default_categories() -> Vec<String> {
+    DEFAULT_CATEGORIES.iter().map(|c| c.to_string()).collect()
+}
+
+/// The category and the tags of an entry, used to group and filter the entries.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct Labels {
+    #[serde(default)]
+    pub category: Option<String>,
+    #[serde(default)]
+    pub tags: BTreeSet<String>,
+}
+
+impl Labels {
+    /// Creates the labels of an entry.
+    /// # Arguments
+    /// - `category`: The category, if there is one.
+    /// - `tags`: The tags, separated by commas.
+    /// # Returns
+    /// - The labels.
+    /// # Examples
+    /// ```
+    /// use nix_bucks::Labels;
+    ///
+    /// fn main() {
+    ///     let labels = Labels::new(Some(String::from("Food")), "groceries, , weekly ");
+    ///
+    ///     assert_eq!(labels.tags_to_string(), "groceries, weekly");
+    /// }
+    /// ```
+    pub fn new(category: Option<String>, tags: &str) -> Self {
+        Self {
+            category,
+            tags: tags
+                .split(',')
+                .map(str::trim)
+                .filter(|tag| !tag.is_empty())
+                .map(String::from)
+                .collect(),
+        }
+    }
+
+    /// Returns the tags separated by commas.
+    pub fn tags_to_string(&self) -> String {
+        self.tags
+            .iter()
+            .cloned()
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+}
+
+/// Selects the entries with the given category and tag. An empty filter selects every entry.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LabelFilter {
+    pub category: Option<String>,
+    pub tag: Option<String>,
+}
+
+impl LabelFilter {
+    /// Returns true if the labels have the category and the tag of the filter.
+    pub fn matches(&self, labels: &Labels) -> bool {
+        self.category
+            .as_ref()
+            .is_none_or(|category| labels.category.as_ref() == Some(category))
+            && self
+                .tag
+                .as_ref()
+                .is_none_or(|tag| labels.tags.contains(tag))
+    }
+}
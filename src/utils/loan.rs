@@ -0,0 +1,289 @@
+use chrono::{Months, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{Labels, Money};
+
+/// A temporary loan is a loan that is not yet saved.
+/// It's used to create a new loan.
+#[derive(Clone)]
+pub struct TmpLoan {
+    pub name: String,
+    pub principal: f64,
+    /// The annual interest rate, as a percentage.
+    pub rate: f64,
+    pub term: u32,
+    pub first: NaiveDate,
+    pub category: Option<String>,
+    pub tags: String,
+    pub account: Option<Uuid>,
+}
+
+impl Default for TmpLoan {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            principal: 0.0,
+            rate: 0.0,
+            term: 360,
+            first: Utc::now().naive_utc().date(),
+            category: None,
+            tags: String::new(),
+            account: None,
+        }
+    }
+}
+
+impl From<TmpLoan> for Loan {
+    fn from(val: TmpLoan) -> Self {
+        let mut loan = Loan::new(
+            val.name,
+            Money::from_f64(val.principal),
+            (val.rate * 100.0).round().max(0.0) as u32,
+            val.term,
+            val.first,
+        );
+
+        loan.set_labels(Labels::new(val.category, &val.tags));
+        loan.set_account(val.account);
+
+        loan
+    }
+}
+
+/// A monthly payment of a loan.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LoanPayment {
+    /// The date of the payment.
+    pub date: NaiveDate,
+    /// The amount paid.
+    pub payment: Money,
+    /// The part of the payment that goes to interest.
+    pub interest: Money,
+    /// The part of the payment that pays back the principal.
+    pub principal: Money,
+    /// The outstanding balance after the payment.
+    pub balance: Money,
+}
+
+/// A loan (e.g. a mortgage) paid back in equal monthly payments.
+#[derive(Clone, Debug, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct Loan {
+    uuid: Uuid,
+    name: String,
+    principal: Money,
+    /// The annual interest rate in basis points (hundredths of a percent), so 3.5% is 350.
+    annual_rate: u32,
+    term: u32,
+    first: NaiveDate,
+    #[serde(default)]
+    labels: Labels,
+    #[serde(default)]
+    account: Option<Uuid>,
+}
+
+impl Loan {
+    /// Create a new loan.
+    /// # Arguments
+    /// - `name`: The name of the loan.
+    /// - `principal`: The amount borrowed.
+    /// - `annual_rate`: The annual interest rate in basis points (hundredths of a percent).
+    /// - `term`: The amount of monthly payments. It must not be zero.
+    /// - `first`: The date of the first payment. The rest are paid on the same day of the
+    ///   following months.
+    /// # Returns
+    /// - A new loan.
+    /// # Examples
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use nix_bucks::{Loan, Money};
+    ///
+    /// fn main() {
+    ///     let mortgage = Loan::new(
+    ///         String::from("Mortgage"),
+    ///         Money::from_cents(15000000),
+    ///         300,
+    ///         360,
+    ///         NaiveDate::from_ymd_opt(2023, 6, 1).unwrap(),
+    ///     );
+    ///
+    ///     assert_eq!(mortgage.monthly_payment(), Money::from_cents(63241));
+    ///     assert_eq!(mortgage.schedule().last().unwrap().balance, Money::ZERO);
+    /// }
+    /// ```
+    pub fn new(
+        name: String,
+        principal: Money,
+        annual_rate: u32,
+        term: u32,
+        first: NaiveDate,
+    ) -> Self {
+        Self {
+            uuid: Uuid::new_v4(),
+            name,
+            principal,
+            annual_rate,
+            term: term.max(1),
+            first,
+            labels: Labels::default(),
+            account: None,
+        }
+    }
+
+    /// Returns the uuid
+    pub fn uuid(&self) -> Uuid {
+        self.uuid
+    }
+
+    /// Returns the name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the amount borrowed
+    pub fn principal(&self) -> Money {
+        self.principal
+    }
+
+    /// Returns the annual interest rate in basis points (hundredths of a percent)
+    pub fn annual_rate(&self) -> u32 {
+        self.annual_rate
+    }
+
+    /// Returns the amount of monthly payments
+    pub fn term(&self) -> u32 {
+        self.term
+    }
+
+    /// Returns the date of the first payment
+    pub fn first(&self) -> NaiveDate {
+        self.first
+    }
+
+    /// Returns the category and the tags
+    pub fn labels(&self) -> &Labels {
+        &self.labels
+    }
+
+    /// Sets the category and the tags.
+    /// # Arguments
+    /// - `labels`: The new labels.
+    pub fn set_labels(&mut self, labels: Labels) {
+        self.labels = labels;
+    }
+
+    /// Returns the account the entry is charged to or paid into (`None` is the main account)
+    pub fn account(&self) -> Option<Uuid> {
+        self.account
+    }
+
+    /// Sets the account the entry is charged to or paid into.
+    /// # Arguments
+    /// - `account`: The account (`None` is the main account).
+    pub fn set_account(&mut self, account: Option<Uuid>) {
+        self.account = account;
+    }
+
+    /// Returns the monthly interest rate, as a fraction (e.g. 0.0025 for 3% a year).
+    pub fn monthly_rate(&self) -> f64 {
+        self.annual_rate as f64 / 10000.0 / 12.0
+    }
+
+    /// Returns the amount of the monthly payment, so that the loan is paid back in `term`
+    /// payments. The last payment may differ by some cents to clear the balance.
+    pub fn monthly_payment(&self) -> Money {
+        let rate = self.monthly_rate();
+
+        if rate == 0.0 {
+            return self.principal.mul_ratio(1, self.term as i64);
+        }
+
+        self.principal
+            .mul_f64(rate / (1.0 - (1.0 + rate).powi(-(self.term as i32))))
+    }
+
+    /// Returns the amortisation schedule: every payment with its split between interest and
+    /// principal, and the outstanding balance after it.
+    /// # Returns
+    /// - The payments, in chronological order.
+    pub fn schedule(&self) -> Vec<LoanPayment> {
+        let rate = self.monthly_rate();
+        let payment = self.monthly_payment();
+        let mut balance = self.principal;
+
+        (0..self.term)
+            .map_while(|i| {
+                if balance <= Money::ZERO {
+                    return None;
+                }
+
+                let date = self.first.checked_add_months(Months::new(i))?;
+                let interest = balance.mul_f64(rate);
+                let principal = if i + 1 == self.term {
+                    balance
+                } else {
+                    (payment - interest).min(balance)
+                };
+                balance -= principal;
+
+                Some(LoanPayment {
+                    date,
+                    payment: principal + interest,
+                    interest,
+                    principal,
+                    balance,
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the payments between the two given dates (both included).
+    /// # Arguments
+    /// - `from`: The starting date.
+    /// - `to`: The target date.
+    /// # Returns
+    /// - The dates and the amounts of the payments, in chronological order.
+    pub fn charges(&self, from: NaiveDate, to: NaiveDate) -> Vec<(NaiveDate, Money)> {
+        self.schedule()
+            .into_iter()
+            .filter(|p| from <= p.date && p.date <= to)
+            .map(|p| (p.date, p.payment))
+            .collect()
+    }
+
+    /// Calculates the cost from today until the given date.
+    /// # Arguments
+    /// - `to`: The date until the cost should be calculated.
+    /// # Returns
+    /// - The cost from today until the given date.
+    pub fn cost_until(&self, to: NaiveDate) -> Money {
+        self.charges(Utc::now().naive_utc().date(), to)
+            .into_iter()
+            .map(|(_, cost)| cost)
+            .sum()
+    }
+
+    /// Returns the outstanding balance after the payments made up to the given date (included).
+    pub fn balance_on(&self, date: NaiveDate) -> Money {
+        self.schedule()
+            .into_iter()
+            .take_while(|p| p.date <= date)
+            .last()
+            .map_or(self.principal, |p| p.balance)
+    }
+
+    /// Returns the interest paid over the whole life of the loan.
+    pub fn total_interest(&self) -> Money {
+        self.schedule().into_iter().map(|p| p.interest).sum()
+    }
+
+    /// Returns the date of the last payment.
+    pub fn last_payment(&self) -> NaiveDate {
+        self.schedule().last().map_or(self.first, |p| p.date)
+    }
+
+    /// Returns true if every payment has been made by the given date (included).
+    pub fn is_finished(&self, date: NaiveDate) -> bool {
+        self.last_payment() <= date
+    }
+}
@@ -1,17 +1,106 @@
+use std::io::Read;
+
+use directories::ProjectDirs;
+use serde::de::DeserializeOwned;
+
+mod account;
+mod budget;
+mod calendar;
 mod fixed_expense;
+mod forecast;
+mod goal;
+mod household;
+mod inflation;
+mod installment;
+mod interest;
+mod labels;
+mod loan;
+mod money;
+mod payoff;
 mod recurrence;
 mod subscription;
+mod tax;
+mod transaction;
+mod transfer;
+mod uncertainty;
 
+pub use account::Account;
+pub use budget::{Budget, BudgetStatus};
+pub use calendar::{BusinessDayRule, HolidayCalendar};
 pub use fixed_expense::FixedExpense;
-pub use recurrence::{times_until, Recurrence, SimpleRecurrence};
-pub use subscription::{Subscription, TmpSubscription};
+pub use forecast::{FlowKind, Forecast, ForecastEntry};
+pub use goal::{allocate_contributions, allocate_savings, Goal};
+pub use household::{settle, Member, Settlement, ShareRule, Split};
+pub use inflation::{escalate, to_real};
+pub use installment::{Installment, TmpInstallment};
+pub use interest::{compounding_to_lang_str, Compounding, InterestRate};
+pub use labels::{default_categories, LabelFilter, Labels};
+pub use loan::{Loan, LoanPayment, TmpLoan};
+pub use money::Money;
+pub use payoff::{plan_payoff, PayoffPlan, PayoffStrategy};
+pub use recurrence::{
+    clamp_to_month, days_in_month, months_between, nth_to_lang_str, nth_weekday_of_month,
+    times_until, weekday_to_lang_str, Occurrences, Recurrence, SimpleRecurrence,
+};
+pub use subscription::{OccurrenceOverride, Subscription, SubscriptionDetails, TmpSubscription};
+pub use tax::{TaxBracket, TaxProfile, TaxProfiles};
+pub use transaction::{TmpTransaction, Transaction};
+pub use transfer::{TmpTransfer, Transfer};
+pub use uncertainty::{scenario_to_lang_str, Estimate, Scenario, Uncertainty};
+
+pub(crate) const QUALIFIER: &str = "com";
+pub(crate) const ORGANIZATION: &str = "margual56";
+pub(crate) const APPLICATION: &str = "NixBucks";
+
+/// Loads a value from a JSON file in the config folder.
+/// # Arguments
+/// - `file`: The name of the file, e.g. "holidays.json".
+/// - `what`: What the file holds, for the error messages.
+/// # Returns
+/// - The parsed value, or the default one if the file does not exist or can't be read.
+pub(crate) fn load_config<T: DeserializeOwned + Default>(file: &str, what: &str) -> T {
+    let Some(dir) = ProjectDirs::from(QUALIFIER, ORGANIZATION, APPLICATION) else {
+        return T::default();
+    };
+
+    let mut file = match std::fs::File::open(dir.config_dir().join(file)) {
+        Ok(f) => f,
+        Err(_) => return T::default(),
+    };
+
+    let mut buffer = String::new();
+
+    if let Err(e) = file.read_to_string(&mut buffer) {
+        println!("Error while reading the {}: {}", what, e);
+        return T::default();
+    }
+
+    match serde_json::from_str::<T>(&buffer) {
+        Ok(value) => value,
+        Err(e) => {
+            println!("Error while parsing the {}: {}", what, e);
+            T::default()
+        }
+    }
+}
 
 mod tests {
     use chrono::NaiveDate;
     use once_cell::sync::Lazy;
 
     #[allow(unused)]
-    use crate::{utils::times_until, Recurrence};
+    use chrono::Weekday;
+
+    #[allow(unused)]
+    use crate::{
+        allocate_contributions, allocate_savings, clamp_to_month, days_in_month, escalate,
+        months_between, nth_weekday_of_month, plan_payoff, settle, to_real, utils::times_until,
+        Budget, BudgetStatus, BusinessDayRule, Compounding, Estimate, FixedExpense, FlowKind,
+        Forecast, Goal, HolidayCalendar, Installment, InterestRate, LabelFilter, Labels, Loan,
+        Member, Money, OccurrenceOverride, PayoffStrategy, Recurrence, Scenario, ShareRule, Split,
+        Subscription, SubscriptionDetails, TaxBracket, TaxProfile, TmpTransaction, Transaction,
+        Transfer, Uncertainty,
+    };
 
     #[allow(unused)]
     static START: Lazy<NaiveDate> = Lazy::new(|| NaiveDate::from_ymd_opt(2023, 5, 3).unwrap());
@@ -40,4 +129,1375 @@ mod tests {
         assert_eq!(times_until(Recurrence::Year(1, 1, 2), *START, target), 5);
         assert_eq!(times_until(Recurrence::Year(1, 1, 3), *START, target), 3);
     }
+
+    #[test]
+    fn test_money_arithmetic() {
+        let total: Money = [Money::from_f64(0.1), Money::from_f64(0.2)]
+            .into_iter()
+            .sum();
+
+        assert_eq!(total, Money::from_cents(30));
+        assert_eq!(Money::from_cents(1000) * 3, Money::from_cents(3000));
+        assert_eq!(
+            Money::from_cents(1000).mul_ratio(1, 3),
+            Money::from_cents(333)
+        );
+        assert_eq!(
+            Money::from_cents(-1000).mul_ratio(2, 3),
+            Money::from_cents(-667)
+        );
+        assert_eq!(
+            Money::from_cents(1000).mul_f64(0.155),
+            Money::from_cents(155)
+        );
+    }
+
+    #[test]
+    fn test_money_display() {
+        assert_eq!(format!("{}", Money::from_cents(123456)), "1234.56");
+        assert_eq!(format!("{}", Money::from_cents(-5)), "-0.05");
+        assert_eq!(format!("{:+}", Money::from_cents(5)), "+0.05");
+        assert_eq!(format!("{:+}", Money::from_cents(-150)), "-1.50");
+    }
+
+    #[test]
+    fn test_money_serde() {
+        // Old config files stored amounts as floats
+        let expense: FixedExpense = serde_json::from_str(
+            r#"{"uuid":"67e55044-10b1-426f-9247-bb680e5fe0c8","name":"Laptop","cost":1299.99,"date":"2023-05-03"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(expense.cost(), Money::from_cents(129999));
+
+        let json = serde_json::to_string(&Money::from_cents(123456789)).unwrap();
+        assert_eq!(json, "1234567.89");
+        assert_eq!(
+            serde_json::from_str::<Money>(&json).unwrap(),
+            Money::from_cents(123456789)
+        );
+        assert_eq!(
+            serde_json::from_str::<Money>("12").unwrap(),
+            Money::from_cents(1200)
+        );
+    }
+
+    #[test]
+    fn test_money_normalised_costs() {
+        let subscription = Subscription::new(
+            String::from("Quarterly"),
+            Money::from_cents(1000),
+            Recurrence::Month(1, 3),
+        );
+
+        assert_eq!(subscription.cost_per_month(), Money::from_cents(333));
+        assert_eq!(subscription.cost_per_year(), Money::from_cents(4000));
+
+        // A zero interval from a hand-edited config counts as one instead of dividing by zero
+        let daily = Subscription::new(
+            String::from("Daily"),
+            Money::from_cents(100),
+            Recurrence::Day(0),
+        );
+        assert_eq!(daily.cost_per_year(), Money::from_cents(36500));
+    }
+
+    #[test]
+    fn test_days_in_month() {
+        let lengths = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+
+        for (m, length) in lengths.iter().enumerate() {
+            assert_eq!(days_in_month(2023, m as u32 + 1), *length);
+        }
+
+        assert_eq!(days_in_month(2024, 2), 29);
+        assert_eq!(days_in_month(2000, 2), 29);
+        assert_eq!(days_in_month(2100, 2), 28);
+        assert_eq!(days_in_month(2023, 13), 0);
+    }
+
+    #[test]
+    fn test_times_until_month_end() {
+        let from = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+        let to = NaiveDate::from_ymd_opt(2023, 12, 31).unwrap();
+
+        // Every month has a 31st, a 30th and a 29th once they are clamped
+        for day in 28..=31 {
+            assert_eq!(times_until(Recurrence::Month(day, 1), from, to), 12);
+        }
+
+        // Clamping must not drift: after February, the 31st is still the last day of the month
+        assert_eq!(
+            clamp_to_month(2023, 3, 31),
+            NaiveDate::from_ymd_opt(2023, 3, 31).unwrap()
+        );
+
+        // Starting in a short month must not panic
+        let from = NaiveDate::from_ymd_opt(2023, 2, 15).unwrap();
+        let to = NaiveDate::from_ymd_opt(2023, 4, 30).unwrap();
+        assert_eq!(times_until(Recurrence::Month(31, 1), from, to), 3);
+        assert_eq!(times_until(Recurrence::Month(30, 2), from, to), 2);
+    }
+
+    #[test]
+    fn test_times_until_leap_day() {
+        let from = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+        let to = NaiveDate::from_ymd_opt(2028, 12, 31).unwrap();
+
+        // Once a year, on the 29th of February (or the 28th on non-leap years)
+        assert_eq!(times_until(Recurrence::Year(29, 2, 1), from, to), 6);
+        assert_eq!(times_until(Recurrence::Year(29, 2, 4), from, to), 2);
+
+        let from = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        assert_eq!(times_until(Recurrence::Year(29, 2, 1), from, from), 1);
+
+        let to = NaiveDate::from_ymd_opt(2025, 2, 27).unwrap();
+        assert_eq!(times_until(Recurrence::Year(29, 2, 1), from, to), 1);
+    }
+
+    #[test]
+    fn test_occurrences() {
+        let dates: Vec<NaiveDate> = Recurrence::Day(100).occurrences(*START, *TARGET).collect();
+        assert_eq!(
+            dates,
+            vec![
+                NaiveDate::from_ymd_opt(2023, 8, 11).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 11, 19).unwrap(),
+            ]
+        );
+
+        let dates: Vec<NaiveDate> = Recurrence::Month(3, 4)
+            .occurrences(*START, *TARGET)
+            .collect();
+        assert_eq!(
+            dates,
+            vec![
+                NaiveDate::from_ymd_opt(2023, 5, 3).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 9, 3).unwrap(),
+            ]
+        );
+
+        let target = NaiveDate::from_ymd_opt(2025, 12, 31).unwrap();
+        let dates: Vec<NaiveDate> = Recurrence::Year(2, 5, 1)
+            .occurrences(*START, target)
+            .collect();
+        assert_eq!(
+            dates,
+            vec![
+                NaiveDate::from_ymd_opt(2024, 5, 2).unwrap(),
+                NaiveDate::from_ymd_opt(2025, 5, 2).unwrap(),
+            ]
+        );
+
+        assert_eq!(
+            Recurrence::Month(1, 1).occurrences(*TARGET, *START).next(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_next_occurrence() {
+        let holidays = HolidayCalendar::default();
+        let subscription = Subscription::new(
+            String::from("Rent"),
+            Money::from_cents(50000),
+            Recurrence::Month(31, 1),
+        );
+
+        let from = NaiveDate::from_ymd_opt(2024, 2, 10).unwrap();
+        assert_eq!(
+            subscription.next_occurrence(from, &holidays),
+            NaiveDate::from_ymd_opt(2024, 2, 29)
+        );
+    }
+
+    #[test]
+    fn test_weekly_occurrences() {
+        // Every other Friday, the first one being the 5th of May 2023
+        let anchor = NaiveDate::from_ymd_opt(2023, 5, 3).unwrap();
+        let recurrence = Recurrence::Week(Weekday::Fri, 2, anchor);
+
+        let from = NaiveDate::from_ymd_opt(2023, 5, 10).unwrap();
+        let to = NaiveDate::from_ymd_opt(2023, 6, 16).unwrap();
+        let dates: Vec<NaiveDate> = recurrence.occurrences(from, to).collect();
+
+        assert_eq!(
+            dates,
+            vec![
+                NaiveDate::from_ymd_opt(2023, 5, 19).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 6, 2).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 6, 16).unwrap(),
+            ]
+        );
+
+        // Every Monday, counted from a Wednesday (the 3rd of May 2023)
+        let recurrence = Recurrence::Week(Weekday::Mon, 1, *START);
+        assert_eq!(times_until(recurrence, *START, *TARGET), 34);
+
+        // Nothing happens before the anchor
+        let recurrence = Recurrence::Week(Weekday::Wed, 1, *TARGET);
+        assert_eq!(times_until(recurrence, *START, *TARGET), 0);
+    }
+
+    #[test]
+    fn test_weekly_normalised_costs() {
+        let subscription = Subscription::new(
+            String::from("Cleaning"),
+            Money::from_cents(7000),
+            Recurrence::Week(Weekday::Mon, 2, *START),
+        );
+
+        assert_eq!(subscription.cost_per_year(), Money::from_cents(182500));
+        assert_eq!(subscription.cost_per_month(), Money::from_cents(15208));
+    }
+
+    #[test]
+    fn test_nth_weekday_of_month() {
+        // June 2023 starts on a Thursday and has five Thursdays and Fridays
+        assert_eq!(
+            nth_weekday_of_month(2023, 6, 1, Weekday::Thu),
+            NaiveDate::from_ymd_opt(2023, 6, 1).unwrap()
+        );
+        assert_eq!(
+            nth_weekday_of_month(2023, 6, 1, Weekday::Mon),
+            NaiveDate::from_ymd_opt(2023, 6, 5).unwrap()
+        );
+        assert_eq!(
+            nth_weekday_of_month(2023, 6, 4, Weekday::Wed),
+            NaiveDate::from_ymd_opt(2023, 6, 28).unwrap()
+        );
+        assert_eq!(
+            nth_weekday_of_month(2023, 6, -1, Weekday::Mon),
+            NaiveDate::from_ymd_opt(2023, 6, 26).unwrap()
+        );
+        assert_eq!(
+            nth_weekday_of_month(2023, 6, -2, Weekday::Fri),
+            NaiveDate::from_ymd_opt(2023, 6, 23).unwrap()
+        );
+
+        // February 2026 only has four of each day, so the fifth one is the last one
+        assert_eq!(
+            nth_weekday_of_month(2026, 2, 5, Weekday::Sun),
+            NaiveDate::from_ymd_opt(2026, 2, 22).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_month_weekday_occurrences() {
+        // The last Friday of every month
+        let recurrence = Recurrence::MonthWeekday(-1, Weekday::Fri, 1);
+        let dates: Vec<NaiveDate> = recurrence
+            .occurrences(*START, NaiveDate::from_ymd_opt(2023, 8, 31).unwrap())
+            .collect();
+
+        assert_eq!(
+            dates,
+            vec![
+                NaiveDate::from_ymd_opt(2023, 5, 26).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 6, 30).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 7, 28).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 8, 25).unwrap(),
+            ]
+        );
+
+        // The first Monday of May has already passed, so July, September and November
+        let recurrence = Recurrence::MonthWeekday(1, Weekday::Mon, 2);
+        assert_eq!(times_until(recurrence, *START, *TARGET), 3);
+
+        let subscription =
+            Subscription::new(String::from("Water"), Money::from_cents(3000), recurrence);
+        assert_eq!(subscription.cost_per_month(), Money::from_cents(1500));
+    }
+
+    #[test]
+    fn test_business_day_adjustment() {
+        // Monday the 1st of May 2023 is a bank holiday
+        let holidays = HolidayCalendar::new([NaiveDate::from_ymd_opt(2023, 5, 1).unwrap()]);
+
+        // Sunday the 30th of April 2023
+        let date = NaiveDate::from_ymd_opt(2023, 4, 30).unwrap();
+
+        assert_eq!(holidays.adjust(date, BusinessDayRule::None), date);
+        assert_eq!(
+            holidays.adjust(date, BusinessDayRule::Following),
+            NaiveDate::from_ymd_opt(2023, 5, 2).unwrap()
+        );
+        assert_eq!(
+            holidays.adjust(date, BusinessDayRule::Preceding),
+            NaiveDate::from_ymd_opt(2023, 4, 28).unwrap()
+        );
+        assert_eq!(
+            holidays.adjust(date, BusinessDayRule::ModifiedFollowing),
+            NaiveDate::from_ymd_opt(2023, 4, 28).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_business_day_occurrences() {
+        let holidays = HolidayCalendar::new([NaiveDate::from_ymd_opt(2023, 5, 1).unwrap()]);
+
+        let mut subscription = Subscription::new(
+            String::from("Salary"),
+            Money::from_cents(200000),
+            Recurrence::Month(30, 1),
+        );
+        subscription.set_business_day_rule(BusinessDayRule::Following);
+
+        // The 30th of April is moved to the 2nd of May, so it's counted in May
+        let from = NaiveDate::from_ymd_opt(2023, 5, 1).unwrap();
+        let to = NaiveDate::from_ymd_opt(2023, 7, 31).unwrap();
+        let dates: Vec<NaiveDate> = subscription
+            .occurrences(NaiveDate::from_ymd_opt(2023, 4, 1).unwrap(), to, &holidays)
+            .filter(|date| *date >= from)
+            .collect();
+
+        assert_eq!(
+            dates,
+            vec![
+                NaiveDate::from_ymd_opt(2023, 5, 2).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 5, 30).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 6, 30).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 7, 31).unwrap(),
+            ]
+        );
+
+        // Querying from the holiday finds the payment moved into the period
+        let dates: Vec<NaiveDate> = subscription.occurrences(from, to, &holidays).collect();
+        assert_eq!(
+            dates,
+            vec![
+                NaiveDate::from_ymd_opt(2023, 5, 2).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 5, 30).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 6, 30).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 7, 31).unwrap(),
+            ]
+        );
+
+        // The 30th of July 2023 is a Sunday, which is moved after the end of the period
+        let to = NaiveDate::from_ymd_opt(2023, 7, 30).unwrap();
+        assert_eq!(subscription.occurrences(from, to, &holidays).count(), 3);
+    }
+
+    #[test]
+    fn test_subscription_period() {
+        let holidays = HolidayCalendar::default();
+
+        // A 12-month phone contract
+        let mut subscription = Subscription::new(
+            String::from("Phone"),
+            Money::from_cents(1500),
+            Recurrence::Month(15, 1),
+        );
+        subscription.set_period(
+            NaiveDate::from_ymd_opt(2023, 3, 15),
+            NaiveDate::from_ymd_opt(2024, 2, 29),
+        );
+
+        let to = NaiveDate::from_ymd_opt(2025, 12, 31).unwrap();
+        assert_eq!(subscription.occurrences(*START, to, &holidays).count(), 10);
+
+        let from = NaiveDate::from_ymd_opt(2020, 1, 1).unwrap();
+        assert_eq!(subscription.occurrences(from, to, &holidays).count(), 12);
+
+        assert!(!subscription.is_active(NaiveDate::from_ymd_opt(2023, 3, 14).unwrap()));
+        assert!(subscription.is_active(*START));
+        assert!(!subscription.is_active(to));
+
+        // An end before the start is swapped instead of never charging anything
+        subscription.set_period(
+            NaiveDate::from_ymd_opt(2024, 2, 29),
+            NaiveDate::from_ymd_opt(2023, 3, 15),
+        );
+        assert_eq!(subscription.start(), NaiveDate::from_ymd_opt(2023, 3, 15));
+        assert_eq!(subscription.end(), NaiveDate::from_ymd_opt(2024, 2, 29));
+        assert_eq!(subscription.occurrences(from, to, &holidays).count(), 12);
+    }
+
+    #[test]
+    fn test_subscription_start_anchor() {
+        let holidays = HolidayCalendar::default();
+
+        // Every 10 days, starting on the 1st of May
+        let mut subscription = Subscription::new(
+            String::from("Delivery"),
+            Money::from_cents(500),
+            Recurrence::Day(10),
+        );
+        subscription.set_period(NaiveDate::from_ymd_opt(2023, 5, 1), None);
+
+        let dates: Vec<NaiveDate> = subscription
+            .occurrences(
+                *START,
+                NaiveDate::from_ymd_opt(2023, 5, 31).unwrap(),
+                &holidays,
+            )
+            .collect();
+
+        assert_eq!(
+            dates,
+            vec![
+                NaiveDate::from_ymd_opt(2023, 5, 11).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 5, 21).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 5, 31).unwrap(),
+            ]
+        );
+
+        // Every 2 months, counted from the start date instead of from today
+        let mut subscription = Subscription::new(
+            String::from("Haircut"),
+            Money::from_cents(2000),
+            Recurrence::Month(1, 2),
+        );
+        subscription.set_period(NaiveDate::from_ymd_opt(2023, 6, 1), None);
+
+        assert_eq!(
+            subscription.next_occurrence(*START, &holidays),
+            NaiveDate::from_ymd_opt(2023, 6, 1)
+        );
+        assert_eq!(
+            subscription.next_occurrence(NaiveDate::from_ymd_opt(2023, 7, 1).unwrap(), &holidays),
+            NaiveDate::from_ymd_opt(2023, 8, 1)
+        );
+    }
+
+    #[test]
+    fn test_price_changes() {
+        let holidays = HolidayCalendar::default();
+
+        let mut subscription = Subscription::new(
+            String::from("Streaming"),
+            Money::from_cents(999),
+            Recurrence::Month(10, 1),
+        );
+        subscription.add_price_change(
+            NaiveDate::from_ymd_opt(2023, 9, 1).unwrap(),
+            Money::from_cents(1299),
+        );
+        subscription.add_price_change(
+            NaiveDate::from_ymd_opt(2023, 12, 10).unwrap(),
+            Money::from_cents(1499),
+        );
+
+        assert_eq!(subscription.cost_on(*START), Money::from_cents(999));
+        assert_eq!(subscription.cost_on(*TARGET), Money::from_cents(1499));
+
+        // May-August at 9.99, September-November at 12.99 and December at 14.99
+        let total: Money = subscription
+            .charges(*START, *TARGET, &holidays)
+            .map(|(_, cost)| cost)
+            .sum();
+        assert_eq!(total, Money::from_cents(4 * 999 + 3 * 1299 + 1499));
+
+        subscription.remove_price_change(NaiveDate::from_ymd_opt(2023, 12, 10).unwrap());
+        assert_eq!(subscription.cost_on(*TARGET), Money::from_cents(1299));
+    }
+
+    #[test]
+    fn test_installment_schedule() {
+        // 1000€ in 3 monthly payments, starting on the 31st of January
+        let installment = Installment::new(
+            String::from("Laptop"),
+            Money::from_cents(100000),
+            3,
+            NaiveDate::from_ymd_opt(2024, 1, 31).unwrap(),
+            Recurrence::Month(31, 1),
+        );
+
+        assert_eq!(
+            installment.schedule(),
+            vec![
+                (
+                    NaiveDate::from_ymd_opt(2024, 1, 31).unwrap(),
+                    Money::from_cents(33334)
+                ),
+                (
+                    NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(),
+                    Money::from_cents(33333)
+                ),
+                (
+                    NaiveDate::from_ymd_opt(2024, 3, 31).unwrap(),
+                    Money::from_cents(33333)
+                ),
+            ]
+        );
+
+        let date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        assert_eq!(installment.paid_payments(date), 2);
+        assert_eq!(installment.remaining(date), Money::from_cents(33333));
+        assert!(!installment.is_finished(date));
+
+        let date = NaiveDate::from_ymd_opt(2024, 3, 31).unwrap();
+        assert_eq!(installment.remaining(date), Money::ZERO);
+        assert!(installment.is_finished(date));
+    }
+
+    #[test]
+    fn test_label_filter() {
+        let labels = Labels::new(Some(String::from("Entertainment")), " music,streaming , ");
+
+        assert_eq!(labels.tags.len(), 2);
+        assert!(LabelFilter::default().matches(&labels));
+
+        let filter = LabelFilter {
+            category: Some(String::from("Entertainment")),
+            tag: Some(String::from("music")),
+        };
+        assert!(filter.matches(&labels));
+        assert!(!filter.matches(&Labels::default()));
+
+        let filter = LabelFilter {
+            category: None,
+            tag: Some(String::from("video")),
+        };
+        assert!(!filter.matches(&labels));
+    }
+
+    #[test]
+    fn test_transfer_net() {
+        let checking = uuid::Uuid::new_v4();
+        let savings = uuid::Uuid::new_v4();
+
+        // The 1st of each month, from June to December
+        let transfer = Transfer::new(
+            String::from("Savings"),
+            Money::from_cents(5000),
+            Recurrence::Month(1, 1),
+            Some(checking),
+            Some(savings),
+        );
+
+        assert_eq!(
+            transfer.net_between(Some(savings), *START, *TARGET),
+            Money::from_cents(7 * 5000)
+        );
+        assert_eq!(
+            transfer.net_between(Some(checking), *START, *TARGET),
+            Money::from_cents(-7 * 5000)
+        );
+        // The main account is not involved
+        assert_eq!(transfer.net_between(None, *START, *TARGET), Money::ZERO);
+
+        // A transfer to the same account does not move any money
+        let transfer = Transfer::new(
+            String::from("Nothing"),
+            Money::from_cents(5000),
+            Recurrence::Month(1, 1),
+            Some(checking),
+            Some(checking),
+        );
+        assert_eq!(
+            transfer.net_between(Some(checking), *START, *TARGET),
+            Money::ZERO
+        );
+    }
+
+    #[test]
+    fn test_transaction_sign() {
+        let spent: Transaction = TmpTransaction {
+            description: String::from("Groceries"),
+            amount: 42.5,
+            income: false,
+            ..Default::default()
+        }
+        .into();
+        assert_eq!(spent.amount(), Money::from_cents(-4250));
+
+        // The sign of the amount typed in is ignored, only the direction matters
+        let received: Transaction = TmpTransaction {
+            description: String::from("Refund"),
+            amount: -10.0,
+            income: true,
+            ..Default::default()
+        }
+        .into();
+        assert_eq!(received.amount(), Money::from_cents(1000));
+    }
+
+    #[test]
+    fn test_occurrence_overrides() {
+        let holidays = HolidayCalendar::default();
+        let date = |m, d| NaiveDate::from_ymd_opt(2023, m, d).unwrap();
+        let mut subscription = Subscription::new(
+            String::from("Gym"),
+            Money::from_cents(3000),
+            Recurrence::Month(10, 1),
+        );
+
+        // June is skipped, July is more expensive and December is moved to January
+        subscription.set_override(
+            date(6, 10),
+            OccurrenceOverride {
+                skipped: true,
+                ..Default::default()
+            },
+        );
+        subscription.set_override(
+            date(7, 10),
+            OccurrenceOverride {
+                paid: true,
+                amount: Some(Money::from_cents(4500)),
+                ..Default::default()
+            },
+        );
+        subscription.set_override(
+            date(12, 10),
+            OccurrenceOverride {
+                moved_to: Some(NaiveDate::from_ymd_opt(2024, 1, 2).unwrap()),
+                ..Default::default()
+            },
+        );
+
+        let charges: Vec<(NaiveDate, Money)> =
+            subscription.charges(*START, *TARGET, &holidays).collect();
+        assert_eq!(charges.len(), 6);
+        assert_eq!(charges[1], (date(7, 10), Money::from_cents(4500)));
+
+        let total: Money = charges.into_iter().map(|(_, cost)| cost).sum();
+        assert_eq!(total, Money::from_cents(5 * 3000 + 4500));
+
+        // The moved occurrence is charged in the next period
+        let next_year = NaiveDate::from_ymd_opt(2024, 1, 5).unwrap();
+        assert_eq!(
+            subscription.charges(*TARGET, next_year, &holidays).count(),
+            1
+        );
+
+        // Setting the default value removes the change
+        subscription.set_override(date(6, 10), OccurrenceOverride::default());
+        assert_eq!(subscription.overrides().len(), 2);
+
+        // A change left on a date that isn't an occurrence anymore doesn't charge anything
+        let mut moved = subscription.clone();
+        moved.set_override(
+            date(4, 10),
+            OccurrenceOverride {
+                moved_to: Some(date(6, 1)),
+                ..Default::default()
+            },
+        );
+        assert_eq!(moved.charges(*START, *TARGET, &holidays).count(), 8);
+
+        moved.set_period(None, NaiveDate::from_ymd_opt(2023, 3, 31));
+        assert_eq!(moved.charges(*START, *TARGET, &holidays).count(), 0);
+
+        let mut rescheduled = Subscription::new(
+            String::from("Gym"),
+            Money::from_cents(3000),
+            Recurrence::Month(15, 1),
+        );
+        for (date, occurrence) in subscription.overrides() {
+            rescheduled.set_override(*date, *occurrence);
+        }
+        assert_eq!(
+            rescheduled.charges(*TARGET, next_year, &holidays).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_budget_status() {
+        let budget = Budget::new(String::from("Entertainment"), Money::from_cents(5000));
+
+        assert_eq!(budget.status(Money::ZERO), BudgetStatus::Ok);
+        assert_eq!(budget.status(Money::from_cents(3999)), BudgetStatus::Ok);
+        assert_eq!(
+            budget.status(Money::from_cents(4000)),
+            BudgetStatus::Warning
+        );
+        assert_eq!(
+            budget.status(Money::from_cents(5000)),
+            BudgetStatus::Warning
+        );
+        assert_eq!(
+            budget.status(Money::from_cents(5001)),
+            BudgetStatus::Exceeded
+        );
+
+        // Nothing planned never warns, even without any money to spend
+        let empty = Budget::new(String::from("Other"), Money::ZERO);
+        assert_eq!(empty.status(Money::ZERO), BudgetStatus::Ok);
+        assert_eq!(empty.status(Money::from_cents(1)), BudgetStatus::Exceeded);
+    }
+
+    #[test]
+    fn test_goal_contribution() {
+        let car = Goal::new(
+            String::from("Car"),
+            Money::from_cents(600000),
+            NaiveDate::from_ymd_opt(2023, 11, 15).unwrap(),
+            None,
+        );
+
+        // The 15th of November is 6 whole months after the 3rd of May
+        assert_eq!(car.months_left(*START), 6);
+        assert_eq!(car.months_left(*TARGET), 0);
+
+        // 5000€ left in 6 months, rounded up to the cent
+        let saved = Money::from_cents(100000);
+        assert_eq!(
+            car.required_monthly(saved, *START),
+            Money::from_cents(83334)
+        );
+        assert!((car.progress(saved) - 1.0 / 6.0).abs() < f32::EPSILON);
+
+        assert!(car.is_on_track(saved, Money::from_cents(83334), *START));
+        assert!(!car.is_on_track(saved, Money::from_cents(83333), *START));
+
+        // Once the date has passed, the whole remaining amount is needed
+        assert_eq!(
+            car.required_monthly(saved, *TARGET),
+            Money::from_cents(500000)
+        );
+        assert_eq!(
+            car.required_monthly(Money::from_cents(700000), *START),
+            Money::ZERO
+        );
+
+        // Two goals saved in the same account share its balance instead of both counting all of it
+        let holiday = Goal::new(
+            String::from("Holiday"),
+            Money::from_cents(200000),
+            NaiveDate::from_ymd_opt(2023, 8, 1).unwrap(),
+            None,
+        );
+        let goals = [car.clone(), holiday.clone()];
+
+        let saved = allocate_savings(&goals, |_| Money::from_cents(300000));
+        assert_eq!(saved[&holiday.uuid()], Money::from_cents(200000));
+        assert_eq!(saved[&car.uuid()], Money::from_cents(100000));
+
+        let saved = allocate_savings(&goals, |_| Money::from_cents(150000));
+        assert_eq!(saved[&holiday.uuid()], Money::from_cents(150000));
+        assert_eq!(saved[&car.uuid()], Money::ZERO);
+
+        // They share the monthly surplus too: the holiday needs 250€ a month for 2 months, which
+        // leaves the car behind with the 750€ that are left
+        let monthly = Money::from_cents(100000);
+        let contributions = allocate_contributions(&goals, &saved, monthly, *START);
+        assert_eq!(contributions[&holiday.uuid()], Money::from_cents(25000));
+        assert_eq!(contributions[&car.uuid()], Money::from_cents(75000));
+
+        assert!(car.is_on_track(saved[&car.uuid()], monthly, *START));
+        assert!(!car.is_on_track(saved[&car.uuid()], contributions[&car.uuid()], *START));
+        assert!(holiday.is_on_track(
+            saved[&holiday.uuid()],
+            contributions[&holiday.uuid()],
+            *START
+        ));
+    }
+
+    #[test]
+    fn test_loan_schedule() {
+        let loan = Loan::new(
+            String::from("Car loan"),
+            Money::from_cents(120000),
+            1200,
+            12,
+            NaiveDate::from_ymd_opt(2023, 5, 31).unwrap(),
+        );
+        let schedule = loan.schedule();
+
+        assert_eq!(loan.monthly_payment(), Money::from_cents(10662));
+        assert_eq!(schedule.len(), 12);
+
+        // 1% of interest the first month, and the rest pays back the principal
+        assert_eq!(schedule[0].interest, Money::from_cents(1200));
+        assert_eq!(schedule[0].principal, Money::from_cents(9462));
+        assert_eq!(schedule[0].balance, Money::from_cents(110538));
+
+        // The payments are kept at the end of short months
+        assert_eq!(
+            schedule[1].date,
+            NaiveDate::from_ymd_opt(2023, 6, 30).unwrap()
+        );
+        assert_eq!(
+            schedule[2].date,
+            NaiveDate::from_ymd_opt(2023, 7, 31).unwrap()
+        );
+
+        // The last payment clears the balance
+        let principal: Money = schedule.iter().map(|p| p.principal).sum();
+        assert_eq!(principal, loan.principal());
+        assert_eq!(schedule[11].balance, Money::ZERO);
+        assert_eq!(
+            loan.last_payment(),
+            NaiveDate::from_ymd_opt(2024, 4, 30).unwrap()
+        );
+
+        assert_eq!(loan.balance_on(*START), loan.principal());
+        assert_eq!(loan.balance_on(schedule[0].date), schedule[0].balance);
+        assert_eq!(loan.charges(*START, *TARGET).len(), 8);
+        assert!(!loan.is_finished(*TARGET));
+
+        // Without interest, the principal is split in equal payments
+        let free = Loan::new(String::from("Sofa"), Money::from_cents(1000), 0, 3, *START);
+        assert_eq!(free.total_interest(), Money::ZERO);
+        assert_eq!(
+            free.schedule()
+                .iter()
+                .map(|p| p.payment)
+                .collect::<Vec<_>>(),
+            vec![
+                Money::from_cents(333),
+                Money::from_cents(333),
+                Money::from_cents(334)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_payoff_strategies() {
+        let small = Loan::new(
+            String::from("Small"),
+            Money::from_cents(50000),
+            500,
+            12,
+            *START,
+        );
+        let big = Loan::new(
+            String::from("Big"),
+            Money::from_cents(300000),
+            2000,
+            36,
+            *START,
+        );
+        let loans = [small.clone(), big.clone()];
+        let extra = Money::from_cents(20000);
+
+        // Without extra money, a loan follows its own schedule
+        let alone = plan_payoff(
+            std::slice::from_ref(&big),
+            Money::ZERO,
+            *START,
+            PayoffStrategy::Snowball,
+            &[],
+        );
+        assert_eq!(alone.debt_free(), Some(big.last_payment()));
+        assert_eq!(
+            alone.total_interest,
+            big.total_interest() - big.schedule()[0].interest
+        );
+
+        // The payment of a loan paid off goes to the rest, even without extra money
+        let minimum = plan_payoff(&loans, Money::ZERO, *START, PayoffStrategy::Snowball, &[]);
+        assert_eq!(
+            minimum.payoff_date(small.uuid()),
+            Some(small.last_payment())
+        );
+        assert!(minimum.debt_free() < Some(big.last_payment()));
+
+        // The snowball pays the smallest debt first, the avalanche the most expensive one
+        let snowball = plan_payoff(&loans, extra, *START, PayoffStrategy::Snowball, &[]);
+        let avalanche = plan_payoff(&loans, extra, *START, PayoffStrategy::Avalanche, &[]);
+        assert_eq!(snowball.payoff_dates[0].0, small.uuid());
+        assert!(avalanche.payoff_date(big.uuid()) <= snowball.payoff_date(big.uuid()));
+        assert!(avalanche.total_interest < snowball.total_interest);
+        assert!(snowball.total_interest < minimum.total_interest);
+
+        // A custom order with the most expensive debt first is the avalanche
+        let custom = plan_payoff(
+            &loans,
+            extra,
+            *START,
+            PayoffStrategy::Custom,
+            &[big.uuid(), small.uuid()],
+        );
+        assert_eq!(custom.payoff_dates, avalanche.payoff_dates);
+        assert_eq!(custom.total_interest, avalanche.total_interest);
+    }
+
+    #[test]
+    fn test_interest_accrual() {
+        let balance = Money::from_cents(1000000);
+
+        // From the 3rd of May to the 31st of December there are 7 whole months and 242 days
+        assert_eq!(months_between(*START, *TARGET), 7);
+        assert_eq!(Compounding::Daily.periods_between(*START, *TARGET), 242);
+        assert_eq!(Compounding::Quarterly.periods_between(*START, *TARGET), 2);
+        assert_eq!(Compounding::Yearly.periods_between(*START, *TARGET), 0);
+
+        let monthly = InterestRate::new(300, Compounding::Monthly);
+        assert_eq!(
+            monthly.accrued(balance, *START, *TARGET),
+            Money::from_cents(17632)
+        );
+
+        // The interest of a period is only paid when it ends
+        let yearly = InterestRate::new(300, Compounding::Yearly);
+        assert_eq!(yearly.accrued(balance, *START, *TARGET), Money::ZERO);
+
+        // Debt and a zero rate earn nothing
+        assert_eq!(monthly.accrued(-balance, *START, *TARGET), Money::ZERO);
+        assert_eq!(
+            InterestRate::default().accrued(balance, *START, *TARGET),
+            Money::ZERO
+        );
+    }
+
+    #[test]
+    fn test_inflation() {
+        let holidays = HolidayCalendar::default();
+        let in_two_years = NaiveDate::from_ymd_opt(2025, 5, 3).unwrap();
+        let amount = Money::from_cents(10000);
+
+        // Prices only go up on every whole year
+        assert_eq!(escalate(amount, 500, *START, *TARGET), amount);
+        assert_eq!(
+            escalate(amount, 500, *START, in_two_years),
+            Money::from_cents(11025)
+        );
+        assert_eq!(
+            to_real(Money::from_cents(11025), 500, *START, in_two_years),
+            amount
+        );
+
+        let mut rent = Subscription::new(String::from("Rent"), amount, Recurrence::Month(10, 1));
+        let to = NaiveDate::from_ymd_opt(2025, 6, 30).unwrap();
+        let charges: Vec<(NaiveDate, Money)> = rent
+            .escalated_charges(*START, to, &holidays, 500, *START)
+            .collect();
+
+        assert_eq!(charges[0].1, amount);
+        assert_eq!(charges[12].1, Money::from_cents(10500));
+        assert_eq!(charges.last().unwrap().1, Money::from_cents(11025));
+
+        // A new price is escalated from the date it's set
+        rent.add_price_change(
+            NaiveDate::from_ymd_opt(2024, 6, 1).unwrap(),
+            Money::from_cents(12000),
+        );
+        assert_eq!(
+            rent.escalated_charges(*START, to, &holidays, 500, *START)
+                .last()
+                .unwrap()
+                .1,
+            Money::from_cents(12600)
+        );
+
+        // The rate of the entry wins over the global one
+        rent.set_inflation(Some(0));
+        assert!(rent
+            .escalated_charges(*START, to, &holidays, 500, *START)
+            .all(|(date, cost)| cost == rent.cost_on(date)));
+    }
+
+    #[test]
+    fn test_tax_profiles() {
+        let profile = TaxProfile {
+            name: String::from("Test"),
+            brackets: vec![
+                TaxBracket {
+                    from: Money::from_cents(2000000),
+                    rate: 3000,
+                },
+                TaxBracket {
+                    from: Money::ZERO,
+                    rate: 1000,
+                },
+            ],
+            social_security: 500,
+        };
+
+        // The brackets apply in order whatever the order they are listed in
+        assert_eq!(
+            profile.income_tax(Money::from_cents(1000000)),
+            Money::from_cents(100000)
+        );
+        assert_eq!(
+            profile.income_tax(Money::from_cents(3420000)),
+            Money::from_cents(200000 + 426000)
+        );
+        assert_eq!(
+            profile.yearly_net(Money::from_cents(3600000)),
+            Money::from_cents(3600000 - 180000 - 626000)
+        );
+
+        // The taxes of the year are spread evenly over the monthly payments
+        let mut salary = Subscription::new(
+            String::from("Salary"),
+            Money::from_cents(300000),
+            Recurrence::Month(1, 1),
+        );
+        assert_eq!(salary.cost_on(*START), Money::from_cents(300000));
+
+        salary.set_tax_profile(Some(String::from("Test")));
+        salary.set_tax(Some(profile));
+        assert_eq!(salary.gross_on(*START), Money::from_cents(300000));
+        assert_eq!(salary.cost_on(*START), Money::from_cents(232833));
+        assert_eq!(salary.cost_per_year(), Money::from_cents(232833 * 12));
+    }
+
+    #[test]
+    fn test_uncertain_entries() {
+        let bill = Uncertainty::new(Money::from_cents(4000), Money::from_cents(9000), 100);
+        let base = Money::from_cents(6000);
+
+        assert_eq!(
+            bill.estimate(base, base, Estimate::Low),
+            Money::from_cents(4000)
+        );
+        assert_eq!(bill.estimate(base, base, Estimate::Expected), base);
+        assert_eq!(
+            bill.estimate(base, base, Estimate::High),
+            Money::from_cents(9000)
+        );
+
+        // An entry that might not happen is left out of the low estimate
+        let bonus = Uncertainty::new(Money::from_cents(50000), Money::from_cents(150000), 50);
+        let amount = Money::from_cents(100000);
+
+        assert_eq!(bonus.estimate(amount, amount, Estimate::Low), Money::ZERO);
+        assert_eq!(
+            bonus.estimate(amount, amount, Estimate::Expected),
+            Money::from_cents(50000)
+        );
+        assert_eq!(
+            bonus.estimate(amount, amount, Estimate::High),
+            Money::from_cents(150000)
+        );
+
+        // A range that doesn't include the amount is widened
+        let wrong = Uncertainty::new(Money::from_cents(7000), Money::from_cents(5000), 100);
+        assert_eq!(wrong.estimate(base, base, Estimate::Low), base);
+        assert_eq!(wrong.estimate(base, base, Estimate::High), base);
+
+        // Incomes and costs are estimated the other way round
+        assert_eq!(Scenario::Pessimistic.income(), Estimate::Low);
+        assert_eq!(Scenario::Pessimistic.cost(), Estimate::High);
+        assert_eq!(Scenario::Optimistic.income(), Estimate::High);
+        assert_eq!(Scenario::Optimistic.cost(), Estimate::Low);
+
+        let mut power = Subscription::new(String::from("Power"), base, Recurrence::Month(1, 1));
+        assert_eq!(power.estimate(base, Estimate::High), base);
+
+        power.set_uncertainty(Some(bill));
+        power.add_price_change(*TARGET, Money::from_cents(12000));
+        assert_eq!(
+            power.estimate(power.cost_on(*TARGET), Estimate::High),
+            Money::from_cents(18000)
+        );
+
+        let mut repair = FixedExpense::new(String::from("Repair"), base, *TARGET);
+        repair.uncertainty = Some(bill);
+        assert_eq!(repair.estimate(Estimate::Low), Money::from_cents(4000));
+    }
+
+    #[test]
+    fn test_household_split() {
+        let partner = Member::new(String::from("Partner"));
+        let flatmate = Member::new(String::from("Flatmate"));
+        let rent = Money::from_cents(90000);
+
+        let split = Split {
+            paid_by: None,
+            shares: vec![
+                (partner.uuid(), ShareRule::Percentage(5000)),
+                (flatmate.uuid(), ShareRule::Fixed(Money::from_cents(30000))),
+            ],
+        };
+        assert!(split.is_shared());
+        assert_eq!(split.my_share(rent), Money::from_cents(15000));
+        assert_eq!(
+            split.debts(rent),
+            vec![
+                (Some(partner.uuid()), None, Money::from_cents(45000)),
+                (Some(flatmate.uuid()), None, Money::from_cents(30000)),
+            ]
+        );
+
+        // The shares can't add up to more than the cost
+        let greedy = Split {
+            paid_by: None,
+            shares: vec![(partner.uuid(), ShareRule::Fixed(Money::from_cents(100000)))],
+        };
+        assert_eq!(greedy.my_share(rent), Money::ZERO);
+
+        // An entry paid by a member takes part in the household even without any share
+        let paid_by_partner = Split {
+            paid_by: Some(partner.uuid()),
+            shares: Vec::new(),
+        };
+        assert!(!paid_by_partner.is_shared());
+        assert!(paid_by_partner.involves_members());
+        assert!(!Split::default().involves_members());
+
+        // The partner pays the groceries, and I owe them half
+        let mut groceries = Split {
+            paid_by: Some(partner.uuid()),
+            shares: vec![(partner.uuid(), ShareRule::Percentage(5000))],
+        };
+        let debts = split
+            .debts(rent)
+            .into_iter()
+            .chain(groceries.debts(Money::from_cents(40000)));
+        let settlements = settle(debts);
+
+        assert_eq!(settlements.len(), 2);
+        assert_eq!(settlements[0].from, Some(flatmate.uuid()));
+        assert_eq!(settlements[0].to, None);
+        assert_eq!(settlements[0].amount, Money::from_cents(30000));
+        assert_eq!(settlements[1].from, Some(partner.uuid()));
+        assert_eq!(settlements[1].amount, Money::from_cents(25000));
+
+        // Only the user's share of a shared entry is taken out of the forecast balance
+        let holidays = HolidayCalendar::default();
+        let mut shared_rent =
+            Subscription::new(String::from("Rent"), rent, Recurrence::Month(1, 1));
+        shared_rent.set_split(split.clone());
+        let mut forecast = Forecast::new(Money::ZERO, *START, *TARGET, 0);
+        forecast.add_subscription(&shared_rent, FlowKind::Cost, &holidays, Estimate::Expected);
+
+        assert_eq!(
+            forecast.total(FlowKind::Cost, *TARGET),
+            Money::from_cents(-105000)
+        );
+        assert_eq!(forecast.members_share(*TARGET), Money::from_cents(525000));
+
+        // An entry paid and taken in full by a member leaves the balance untouched
+        shared_rent.set_split(Split {
+            paid_by: Some(partner.uuid()),
+            shares: vec![(partner.uuid(), ShareRule::Percentage(10000))],
+        });
+        let mut forecast = Forecast::new(Money::ZERO, *START, *TARGET, 0);
+        forecast.add_subscription(&shared_rent, FlowKind::Cost, &holidays, Estimate::Expected);
+
+        assert_eq!(forecast.balance_on(*TARGET), Money::ZERO);
+        assert_eq!(forecast.members_share(*TARGET), Money::from_cents(630000));
+
+        // A shared one-off expense takes only the user's share out of the balance, both in the
+        // forecast and once it's past
+        let mut sofa = FixedExpense::new(String::from("Sofa"), Money::from_cents(60000), *START);
+        assert_eq!(sofa.my_cost(), Money::from_cents(60000));
+        sofa.split = Split {
+            paid_by: None,
+            shares: vec![(partner.uuid(), ShareRule::Percentage(5000))],
+        };
+        let mut forecast = Forecast::new(Money::ZERO, *START, *TARGET, 0);
+        forecast.add_fixed(&sofa, FlowKind::Cost, Estimate::Expected);
+
+        assert_eq!(sofa.my_cost(), Money::from_cents(30000));
+        assert_eq!(forecast.balance_on(*TARGET), -sofa.my_cost());
+
+        // Removing the member gives the entry back to the user
+        groceries.remove_member(partner.uuid());
+        assert_eq!(groceries, Split::default());
+    }
+
+    #[test]
+    fn test_subscription_details() {
+        let holidays = HolidayCalendar::default();
+        let mut gym = Subscription::new(
+            String::from("Gym"),
+            Money::from_cents(3500),
+            Recurrence::Month(15, 1),
+        );
+        gym.set_labels(Labels::new(Some(String::from("Health")), "sport"));
+
+        assert!(gym.details().is_empty());
+        assert!(gym.matches(""));
+        assert!(gym.matches("gym"));
+        assert!(gym.matches("HEALTH"));
+        assert!(gym.matches("spo"));
+        assert!(!gym.matches("me@example.com"));
+
+        gym.set_details(SubscriptionDetails {
+            email: String::from("Me@Example.com"),
+            cancel_url: String::from("https://gym.example.com/cancel"),
+            notice_days: 30,
+            notes: String::from("Cancel in person at the front desk"),
+        });
+        assert!(gym.matches("me@example.com"));
+        assert!(gym.matches("front desk"));
+        assert!(gym.matches("gym.example"));
+
+        // The payment of the 15th of May is too close to be avoided
+        assert_eq!(
+            gym.cancel_by(*START, &holidays),
+            Some((
+                NaiveDate::from_ymd_opt(2023, 5, 16).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 6, 15).unwrap()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_forecast() {
+        let june = NaiveDate::from_ymd_opt(2023, 6, 1).unwrap();
+        let july = NaiveDate::from_ymd_opt(2023, 7, 1).unwrap();
+        let mut forecast = Forecast::new(Money::from_cents(100000), *START, *TARGET, 0);
+
+        // The changes are sorted by date, whatever the order they are added in
+        forecast.add(july, "Bonus", FlowKind::Income, Money::from_cents(50000));
+        forecast.add_fixed(
+            &FixedExpense::new(String::from("Repair"), Money::from_cents(20000), june),
+            FlowKind::Cost,
+            Estimate::Expected,
+        );
+        assert_eq!(forecast.entries()[0].name, "Repair");
+        assert_eq!(forecast.entries()[0].balance, Money::from_cents(80000));
+        assert_eq!(forecast.entries()[1].balance, Money::from_cents(130000));
+
+        assert_eq!(forecast.balance_on(*START), Money::from_cents(100000));
+        assert_eq!(
+            forecast.balance_on(NaiveDate::from_ymd_opt(2023, 6, 15).unwrap()),
+            Money::from_cents(80000)
+        );
+        assert_eq!(forecast.balance_on(*TARGET), Money::from_cents(130000));
+
+        // The changes outside of the forecast are left out
+        forecast.add(
+            NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+            "Next year",
+            FlowKind::Income,
+            Money::from_cents(100000),
+        );
+        assert_eq!(forecast.entries().len(), 2);
+
+        // The interest paid on a balance that doesn't change adds up to the interest accrued
+        let rate = InterestRate::new(300, Compounding::Monthly);
+        let savings = Some(uuid::Uuid::new_v4());
+        forecast.add_interest(savings, "Savings", Money::from_cents(100000), rate);
+        assert_eq!(
+            forecast.total(FlowKind::Interest, *TARGET),
+            rate.accrued(Money::from_cents(100000), *START, *TARGET)
+        );
+
+        // The transfers move money out of the main account on every occurrence
+        let transfer = Transfer::new(
+            String::from("Savings"),
+            Money::from_cents(10000),
+            Recurrence::Month(1, 1),
+            None,
+            Some(uuid::Uuid::new_v4()),
+        );
+        forecast.add_transfer(&transfer, None);
+        assert_eq!(
+            forecast.total(FlowKind::Transfer, *TARGET),
+            Money::from_cents(-70000)
+        );
+
+        assert_eq!(
+            forecast.total(FlowKind::Cost, *TARGET),
+            Money::from_cents(-20000)
+        );
+        assert_eq!(forecast.total(FlowKind::Income, june), Money::ZERO);
+        assert_eq!(
+            forecast.balance_on(*TARGET),
+            forecast.opening()
+                + forecast.total(FlowKind::Income, *TARGET)
+                + forecast.total(FlowKind::Cost, *TARGET)
+                + forecast.total(FlowKind::Interest, *TARGET)
+                + forecast.total(FlowKind::Transfer, *TARGET)
+        );
+        assert_eq!(
+            forecast
+                .daily_balances()
+                .last()
+                .map(|(_, balance)| *balance),
+            Some(Money::from_cents(61763))
+        );
+
+        // The changes keep the entry and the account they come from, so the reports can be
+        // worked out from a single forecast
+        let repair = forecast
+            .entries()
+            .iter()
+            .find(|change| change.name == "Repair")
+            .unwrap();
+        assert!(repair.entry.is_some());
+        assert_eq!(repair.account, None);
+        assert_eq!(
+            forecast.total_between(june, july, |change| change.entry == repair.entry),
+            Money::from_cents(-20000)
+        );
+        assert_eq!(
+            forecast.total_between(july, july, |change| change.kind == FlowKind::Transfer),
+            Money::from_cents(-10000)
+        );
+
+        let without_transfers = forecast.filtered(Money::ZERO, june, |change| {
+            change.kind != FlowKind::Transfer
+        });
+        assert_eq!(without_transfers.to(), june);
+        assert_eq!(
+            without_transfers.balance_on(june),
+            forecast.total_between(*START, june, |change| change.kind != FlowKind::Transfer)
+        );
+    }
+
+    #[test]
+    fn test_forecast_interest() {
+        let rate = InterestRate::new(300, Compounding::Monthly);
+        let savings = Some(uuid::Uuid::new_v4());
+        let transfer = Transfer::new(
+            String::from("Savings"),
+            Money::from_cents(10000),
+            Recurrence::Month(1, 1),
+            None,
+            savings,
+        );
+
+        // The money transferred into the account earns interest from the day it arrives
+        let mut forecast = Forecast::new(Money::ZERO, *START, *TARGET, 0);
+        forecast.add_transfer(&transfer, savings);
+        forecast.add_interest(savings, "Savings", Money::ZERO, rate);
+
+        let interest = forecast.total(FlowKind::Interest, *TARGET);
+        assert!(interest > Money::ZERO);
+
+        // It earns less than if all of it had been there from the first day
+        assert!(interest < rate.accrued(Money::from_cents(70000), *START, *TARGET));
+
+        // The interest of other accounts doesn't count their money
+        let mut forecast = Forecast::new(Money::ZERO, *START, *TARGET, 0);
+        forecast.add_transfer(&transfer, savings);
+        forecast.add_interest(None, "Main", Money::ZERO, rate);
+        assert_eq!(forecast.total(FlowKind::Interest, *TARGET), Money::ZERO);
+    }
+
+    #[test]
+    fn test_runway() {
+        let holidays = HolidayCalendar::default();
+        let salary = Subscription::new(
+            String::from("Salary"),
+            Money::from_cents(200000),
+            Recurrence::Month(28, 1),
+        );
+        let rent = Subscription::new(
+            String::from("Rent"),
+            Money::from_cents(80000),
+            Recurrence::Month(1, 1),
+        );
+
+        let mut forecast = Forecast::new(Money::from_cents(100000), *START, *TARGET, 0);
+        forecast.add_subscription(&salary, FlowKind::Income, &holidays, Estimate::Expected);
+        forecast.add_subscription(&rent, FlowKind::Cost, &holidays, Estimate::Expected);
+
+        // The salary always comes before the rent, so the balance never goes lower
+        assert_eq!(forecast.lowest(), (*START, Money::from_cents(100000)));
+        assert_eq!(forecast.first_negative(), None);
+
+        // Without the salary, the rent of July can't be paid
+        let july = NaiveDate::from_ymd_opt(2023, 7, 1).unwrap();
+        assert_eq!(
+            forecast.without(FlowKind::Income).first_negative(),
+            Some(july)
+        );
+        assert_eq!(forecast.runway(), Some(1));
+
+        // A big expense before payday makes the balance dip below zero for a while
+        let car = NaiveDate::from_ymd_opt(2023, 6, 10).unwrap();
+        forecast.add(car, "Car", FlowKind::Cost, Money::from_cents(-300000));
+        assert_eq!(forecast.lowest(), (car, Money::from_cents(-80000)));
+        assert_eq!(forecast.first_negative(), Some(car));
+        assert_eq!(
+            forecast.balance_on(NaiveDate::from_ymd_opt(2023, 6, 28).unwrap()),
+            Money::from_cents(120000)
+        );
+        assert_eq!(forecast.runway(), Some(1));
+
+        // A balance that lasts until the end is not a runway
+        let rich = Forecast::new(Money::from_cents(10000000), *START, *TARGET, 0);
+        assert_eq!(rich.runway(), None);
+    }
 }
@@ -0,0 +1,227 @@
+use std::{
+    fmt::Display,
+    iter::Sum,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Divides `numerator` by `denominator`, rounding half away from zero.
+fn div_round(numerator: i128, denominator: i128) -> i128 {
+    let quotient = numerator / denominator;
+    let remainder = numerator % denominator;
+
+    if 2 * remainder.abs() >= denominator.abs() {
+        quotient + numerator.signum() * denominator.signum()
+    } else {
+        quotient
+    }
+}
+
+/// An amount of money, stored as an integer amount of cents so that the arithmetic is exact.
+///
+/// It is serialized as a decimal number of units (e.g. `12.34`), which keeps the config file easy
+/// to edit by hand and makes it possible to read the files written when amounts were floats.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Money(i64);
+
+impl Money {
+    /// No money at all.
+    pub const ZERO: Money = Money(0);
+
+    /// Creates an amount from an integer amount of cents.
+    /// # Arguments
+    /// - `cents`: The amount of cents.
+    /// # Returns
+    /// - The amount of money.
+    pub const fn from_cents(cents: i64) -> Self {
+        Self(cents)
+    }
+
+    /// Creates an amount from a floating point amount of units, rounding it to the nearest cent
+    /// (half away from zero).
+    /// # Arguments
+    /// - `amount`: The amount of units (e.g. euros).
+    /// # Returns
+    /// - The amount of money.
+    /// # Examples
+    /// ```
+    /// use nix_bucks::Money;
+    ///
+    /// fn main() {
+    ///     assert_eq!(Money::from_f64(9.99), Money::from_cents(999));
+    ///     assert_eq!(Money::from_f64(0.125), Money::from_cents(13));
+    ///     assert_eq!(Money::from_f64(-0.125), Money::from_cents(-13));
+    /// }
+    /// ```
+    pub fn from_f64(amount: f64) -> Self {
+        Self((amount * 100.0).round() as i64)
+    }
+
+    /// Returns the amount of cents.
+    pub fn cents(&self) -> i64 {
+        self.0
+    }
+
+    /// Returns the amount as a floating point amount of units. Only meant for displaying and
+    /// editing, never for calculations.
+    pub fn to_f64(&self) -> f64 {
+        self.0 as f64 / 100.0
+    }
+
+    /// Returns true if the amount is below zero.
+    pub fn is_negative(&self) -> bool {
+        self.0 < 0
+    }
+
+    /// Returns the absolute value of the amount.
+    pub fn abs(&self) -> Self {
+        Self(self.0.abs())
+    }
+
+    /// Multiplies the amount by the fraction `numerator / denominator`, rounding the result to the
+    /// nearest cent (half away from zero).
+    /// # Arguments
+    /// - `numerator`: The numerator of the fraction.
+    /// - `denominator`: The denominator of the fraction. It must not be zero.
+    /// # Returns
+    /// - The rounded amount.
+    /// # Examples
+    /// ```
+    /// use nix_bucks::Money;
+    ///
+    /// fn main() {
+    ///     // 10€ every 3 months is 3.33€ per month
+    ///     assert_eq!(Money::from_cents(1000).mul_ratio(1, 3), Money::from_cents(333));
+    ///     assert_eq!(Money::from_cents(1000).mul_ratio(2, 3), Money::from_cents(667));
+    /// }
+    /// ```
+    pub fn mul_ratio(self, numerator: i64, denominator: i64) -> Self {
+        Self(div_round(self.0 as i128 * numerator as i128, denominator as i128) as i64)
+    }
+
+    /// Splits the amount into equal parts. When it can't be split exactly, the first parts are
+    /// one cent bigger, so that the parts always add up to the amount.
+    /// # Arguments
+    /// - `parts`: The amount of parts. It must not be zero.
+    /// # Returns
+    /// - The parts.
+    /// # Examples
+    /// ```
+    /// use nix_bucks::Money;
+    ///
+    /// fn main() {
+    ///     assert_eq!(
+    ///         Money::from_cents(1000).split(3),
+    ///         vec![Money::from_cents(334), Money::from_cents(333), Money::from_cents(333)]
+    ///     );
+    /// }
+    /// ```
+    pub fn split(self, parts: u32) -> Vec<Money> {
+        let base = self.0 / parts as i64;
+        let remainder = self.0 % parts as i64;
+
+        (0..parts as i64)
+            .map(|i| {
+                if i < remainder.abs() {
+                    Self(base + remainder.signum())
+                } else {
+                    Self(base)
+                }
+            })
+            .collect()
+    }
+
+    /// Multiplies the amount by a floating point factor (e.g. a rate), rounding the result to the
+    /// nearest cent (half away from zero).
+    /// # Arguments
+    /// - `factor`: The factor.
+    /// # Returns
+    /// - The rounded amount.
+    pub fn mul_f64(self, factor: f64) -> Self {
+        Self((self.0 as f64 * factor).round() as i64)
+    }
+}
+
+impl Display for Money {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sign = if self.0 < 0 {
+            "-"
+        } else if f.sign_plus() {
+            "+"
+        } else {
+            ""
+        };
+
+        write!(
+            f,
+            "{}{}.{:02}",
+            sign,
+            self.0.unsigned_abs() / 100,
+            self.0.unsigned_abs() % 100
+        )
+    }
+}
+
+impl Add for Money {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl AddAssign for Money {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+impl Sub for Money {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl SubAssign for Money {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl Neg for Money {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+impl Mul<i64> for Money {
+    type Output = Self;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        Self(self.0 * rhs)
+    }
+}
+
+impl Sum for Money {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Money::ZERO, |acc, m| acc + m)
+    }
+}
+
+impl Serialize for Money {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(self.to_f64())
+    }
+}
+
+impl<'de> Deserialize<'de> for Money {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Accepts both integers and floats, so old float-based config files keep working
+        f64::deserialize(deserializer).map(Money::from_f64)
+    }
+}
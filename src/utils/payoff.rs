@@ -0,0 +1,188 @@
+use std::collections::HashMap;
+
+use chrono::{Months, NaiveDate};
+use uuid::Uuid;
+
+use super::{Loan, Money};
+
+/// The maximum amount of months simulated, so that a plan always ends.
+const MAX_MONTHS: u32 = 1200;
+
+/// The order in which the debts are paid down.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PayoffStrategy {
+    /// The smallest outstanding balance first.
+    Snowball,
+    /// The highest interest rate first.
+    Avalanche,
+    /// The order chosen by the user.
+    Custom,
+}
+
+impl PayoffStrategy {
+    /// Every strategy, in the order they are shown.
+    pub const ALL: [PayoffStrategy; 3] = [
+        PayoffStrategy::Snowball,
+        PayoffStrategy::Avalanche,
+        PayoffStrategy::Custom,
+    ];
+}
+
+/// The result of paying down the debts with a strategy.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PayoffPlan {
+    /// The strategy used.
+    pub strategy: PayoffStrategy,
+    /// The date each debt is paid off, in the order they are paid off. The debts that are not paid
+    /// off within 100 years are left out.
+    pub payoff_dates: Vec<(Uuid, NaiveDate)>,
+    /// The interest paid from the starting date until every debt is paid off.
+    pub total_interest: Money,
+    /// The debt left when the simulation ends. It's only above zero if a debt is never paid off.
+    pub remaining: Money,
+}
+
+impl PayoffPlan {
+    /// Returns the date the given debt is paid off, if it is.
+    pub fn payoff_date(&self, loan: Uuid) -> Option<NaiveDate> {
+        self.payoff_dates
+            .iter()
+            .find(|(uuid, _)| *uuid == loan)
+            .map(|(_, date)| *date)
+    }
+
+    /// Returns the date the last debt is paid off, if they all are.
+    pub fn debt_free(&self) -> Option<NaiveDate> {
+        if self.remaining > Money::ZERO {
+            return None;
+        }
+
+        self.payoff_dates.last().map(|(_, date)| *date)
+    }
+}
+
+/// Simulates paying down the given loans month by month. Every month, each loan gets its regular
+/// payment and the extra amount goes to the first loan of the strategy's order that is not paid
+/// off yet. The payments of the loans that are paid off are added to the extra amount, so it grows
+/// as the debts are cleared.
+/// # Arguments
+/// - `loans`: The loans.
+/// - `extra`: The extra amount paid each month, on top of the regular payments.
+/// - `from`: The starting date. The outstanding balances are taken on this date, and the first
+///   payment is simulated a month later.
+/// - `strategy`: The order in which the debts are paid down.
+/// - `custom_order`: The order of the loans for [`PayoffStrategy::Custom`]. The loans that are not
+///   in it go last.
+/// # Returns
+/// - The payoff date of each loan and the total interest paid.
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use nix_bucks::{plan_payoff, Loan, Money, PayoffStrategy};
+///
+/// fn main() {
+///     let start = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+///     let card = Loan::new(String::from("Card"), Money::from_cents(100000), 2000, 24, start);
+///     let car = Loan::new(String::from("Car"), Money::from_cents(500000), 500, 60, start);
+///     let loans = [card, car];
+///
+///     let extra = Money::from_cents(10000);
+///     let snowball = plan_payoff(&loans, extra, start, PayoffStrategy::Snowball, &[]);
+///     let minimum = plan_payoff(&loans, Money::ZERO, start, PayoffStrategy::Snowball, &[]);
+///
+///     assert!(snowball.total_interest < minimum.total_interest);
+///     assert!(snowball.debt_free() < minimum.debt_free());
+/// }
+/// ```
+pub fn plan_payoff(
+    loans: &[Loan],
+    extra: Money,
+    from: NaiveDate,
+    strategy: PayoffStrategy,
+    custom_order: &[Uuid],
+) -> PayoffPlan {
+    let mut order: Vec<&Loan> = loans.iter().filter(|l| !l.is_finished(from)).collect();
+
+    match strategy {
+        PayoffStrategy::Snowball => order.sort_by_key(|l| l.balance_on(from)),
+        PayoffStrategy::Avalanche => {
+            order.sort_by_key(|l| (std::cmp::Reverse(l.annual_rate()), l.balance_on(from)))
+        }
+        PayoffStrategy::Custom => order.sort_by_key(|l| {
+            custom_order
+                .iter()
+                .position(|uuid| *uuid == l.uuid())
+                .unwrap_or(usize::MAX)
+        }),
+    }
+
+    let mut balances: HashMap<Uuid, Money> = order
+        .iter()
+        .map(|l| (l.uuid(), l.balance_on(from)))
+        .collect();
+    let last_payments: HashMap<Uuid, NaiveDate> =
+        order.iter().map(|l| (l.uuid(), l.last_payment())).collect();
+    let mut payoff_dates = Vec::new();
+    let mut total_interest = Money::ZERO;
+
+    for month in 1..=MAX_MONTHS {
+        if balances.values().all(|balance| *balance <= Money::ZERO) {
+            break;
+        }
+
+        let Some(date) = from.checked_add_months(Months::new(month)) else {
+            break;
+        };
+        let started: Vec<&Loan> = order
+            .iter()
+            .copied()
+            .filter(|l| l.first() <= date)
+            .collect();
+
+        // The money of the regular payments of the loans paid off is kept paying down debt
+        let mut available =
+            extra.max(Money::ZERO) + started.iter().map(|l| l.monthly_payment()).sum::<Money>();
+
+        for loan in &started {
+            let balance = balances.get_mut(&loan.uuid()).unwrap();
+
+            if *balance > Money::ZERO {
+                let interest = balance.mul_f64(loan.monthly_rate());
+                total_interest += interest;
+                *balance += interest;
+
+                // The last payment of the schedule clears whatever is left
+                let payment = if date >= last_payments[&loan.uuid()] {
+                    *balance
+                } else {
+                    loan.monthly_payment().min(*balance)
+                };
+                *balance -= payment;
+                available -= payment;
+            }
+        }
+
+        for loan in &started {
+            let balance = balances.get_mut(&loan.uuid()).unwrap();
+
+            if *balance > Money::ZERO {
+                let payment = available.max(Money::ZERO).min(*balance);
+                *balance -= payment;
+                available -= payment;
+
+                if *balance <= Money::ZERO {
+                    payoff_dates.push((loan.uuid(), date));
+                }
+            } else if payoff_dates.iter().all(|(uuid, _)| *uuid != loan.uuid()) {
+                payoff_dates.push((loan.uuid(), date));
+            }
+        }
+    }
+
+    PayoffPlan {
+        strategy,
+        payoff_dates,
+        total_interest,
+        remaining: balances.into_values().filter(|b| *b > Money::ZERO).sum(),
+    }
+}
@@ -1,22 +1,28 @@
 use cached::proc_macro::cached;
-use chrono::{Datelike, Days, Months, NaiveDate};
+use chrono::{Datelike, Days, Duration, NaiveDate, Weekday};
 use internationalization::t;
 
 use serde::{Deserialize, Serialize};
 
-/// Returns the amount of days in a month.
+/// Returns the amount of days in a month, taking leap years into account.
 /// This function is cached: It will only run once for each value you give it. Then, it caches the
 /// result and returns it when you call it again with the same value.
 /// # Arguments
-/// - `m`: The month.
+/// - `year`: The year.
+/// - `m`: The month (1-12).
 /// # Returns
-/// - The amount of days in the month.
-#[allow(dead_code)]
+/// - The amount of days in the month, or 0 if the month is not valid.
 #[cached]
-pub fn days_in_month(m: u8) -> u8 {
+pub fn days_in_month(year: i32, m: u32) -> u32 {
     match m {
         1 => 31,
-        2 => 28, // TODO: Leap years
+        2 => {
+            if NaiveDate::from_ymd_opt(year, 2, 29).is_some() {
+                29
+            } else {
+                28
+            }
+        }
         3 => 31,
         4 => 30,
         5 => 31,
@@ -31,11 +37,128 @@ pub fn days_in_month(m: u8) -> u8 {
     }
 }
 
+/// Returns the given day of the given month. If the month is shorter than `day`, the last day of
+/// the month is returned instead (e.g. the 31st of April becomes the 30th of April, and the 29th of
+/// February becomes the 28th on non-leap years). This is the policy used by every recurrence.
+/// # Arguments
+/// - `year`: The year.
+/// - `month`: The month (1-12).
+/// - `day`: The day of the month.
+/// # Returns
+/// - The date, clamped to the last day of the month.
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use nix_bucks::clamp_to_month;
+///
+/// fn main() {
+///     assert_eq!(clamp_to_month(2023, 2, 31), NaiveDate::from_ymd_opt(2023, 2, 28).unwrap());
+///     assert_eq!(clamp_to_month(2024, 2, 31), NaiveDate::from_ymd_opt(2024, 2, 29).unwrap());
+///     assert_eq!(clamp_to_month(2023, 4, 15), NaiveDate::from_ymd_opt(2023, 4, 15).unwrap());
+/// }
+/// ```
+pub fn clamp_to_month(year: i32, month: u32, day: u32) -> NaiveDate {
+    let month = month.clamp(1, 12);
+    let day = day.clamp(1, days_in_month(year, month));
+
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+/// Returns the amount of whole months between the two given dates. A month is complete when the
+/// same day of the month is reached (or the last day, if the month is shorter).
+/// # Arguments
+/// - `from`: The starting date.
+/// - `to`: The target date.
+/// # Returns
+/// - The amount of months, or zero if `to` is before `from`.
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use nix_bucks::months_between;
+///
+/// fn main() {
+///     let from = NaiveDate::from_ymd_opt(2023, 1, 31).unwrap();
+///
+///     assert_eq!(months_between(from, NaiveDate::from_ymd_opt(2023, 2, 28).unwrap()), 1);
+///     assert_eq!(months_between(from, NaiveDate::from_ymd_opt(2023, 3, 30).unwrap()), 1);
+///     assert_eq!(months_between(from, NaiveDate::from_ymd_opt(2022, 3, 30).unwrap()), 0);
+/// }
+/// ```
+pub fn months_between(from: NaiveDate, to: NaiveDate) -> u32 {
+    let months = (to.year() - from.year()) * 12 + to.month() as i32 - from.month() as i32;
+    let day = clamp_to_month(to.year(), to.month(), from.day()).day();
+
+    (months - i32::from(to.day() < day)).max(0) as u32
+}
+
+/// Returns the `nth` given day of the week of a month. If `nth` is negative, it's counted from the
+/// end of the month (-1 is the last one). Following the same policy as [`clamp_to_month`], if the
+/// month doesn't have that many, the last (or the first, if counting backwards) is returned.
+/// # Arguments
+/// - `year`: The year.
+/// - `month`: The month (1-12).
+/// - `nth`: Which one of the days of the week (1 is the first one, -1 is the last one).
+/// - `weekday`: The day of the week.
+/// # Returns
+/// - The date.
+/// # Examples
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use nix_bucks::nth_weekday_of_month;
+///
+/// fn main() {
+///     // The second Tuesday and the last Friday of June 2023
+///     assert_eq!(
+///         nth_weekday_of_month(2023, 6, 2, Weekday::Tue),
+///         NaiveDate::from_ymd_opt(2023, 6, 13).unwrap()
+///     );
+///     assert_eq!(
+///         nth_weekday_of_month(2023, 6, -1, Weekday::Fri),
+///         NaiveDate::from_ymd_opt(2023, 6, 30).unwrap()
+///     );
+/// }
+/// ```
+pub fn nth_weekday_of_month(year: i32, month: u32, nth: i8, weekday: Weekday) -> NaiveDate {
+    let first = clamp_to_month(year, month, 1);
+    let last = clamp_to_month(year, month, 31);
+
+    if nth >= 0 {
+        let offset = (weekday.num_days_from_monday() as i64
+            - first.weekday().num_days_from_monday() as i64)
+            .rem_euclid(7);
+        let mut date = first + Duration::days(offset + 7 * (nth.max(1) as i64 - 1));
+
+        while date > last {
+            date -= Duration::days(7);
+        }
+
+        date
+    } else {
+        let offset = (last.weekday().num_days_from_monday() as i64
+            - weekday.num_days_from_monday() as i64)
+            .rem_euclid(7);
+        let mut date = last - Duration::days(offset + 7 * (-(nth as i64) - 1));
+
+        while date < first {
+            date += Duration::days(7);
+        }
+
+        date
+    }
+}
+
+/// Returns the clamped date for the month with the given index, counted as `year * 12 + month0`.
+fn clamp_to_month_index(index: i32, day: u32) -> NaiveDate {
+    clamp_to_month(index.div_euclid(12), index.rem_euclid(12) as u32 + 1, day)
+}
+
 /// A simplified version of the recurrence enum.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum SimpleRecurrence {
     Day,
+    Week,
     Month,
+    MonthWeekday,
     Year,
 }
 
@@ -48,7 +171,9 @@ impl SimpleRecurrence {
     pub fn to_lang_str(&self, lang: &str) -> String {
         match self {
             Self::Day => t!("recurrence.simple.day", lang),
+            Self::Week => t!("recurrence.simple.week", lang),
             Self::Month => t!("recurrence.simple.month", lang),
+            Self::MonthWeekday => t!("recurrence.simple.month_weekday", lang),
             Self::Year => t!("recurrence.simple.year", lang),
         }
     }
@@ -59,8 +184,13 @@ impl SimpleRecurrence {
 pub enum Recurrence {
     /// Amount of days
     Day(u8),
+    /// Day of the week, amount of weeks, date from which the first occurrence is counted
+    Week(Weekday, u8, NaiveDate),
     /// Day of the month, amount of months
     Month(u8, u8),
+    /// Which day of the week of the month (1 to 4, or negative to count from the end: -1 is the
+    /// last one), day of the week, amount of months
+    MonthWeekday(i8, Weekday, u8),
     /// Day of the month, month of the year, amount of years
     Year(u8, u8, u8),
 }
@@ -70,18 +200,30 @@ impl Recurrence {
     /// # Arguments
     /// - `value`: The simple recurrence.
     /// - `days`: The amount of days if it's a `Day` recurrence OR the day of the month otherwise.
-    /// - `months`: The amount of months if it's a `Month` recurrence OR the month of the year otherwise.
+    /// - `weeks`: The amount of weeks if it's a `Week` recurrence.
+    /// - `weekday`: The day of the week if it's a `Week` or a `MonthWeekday` recurrence.
+    /// - `nth`: Which day of the week of the month if it's a `MonthWeekday` recurrence.
+    /// - `months`: The amount of months if it's a `Month` or a `MonthWeekday` recurrence OR the
+    ///   month of the year otherwise.
     /// - `years`: The amount of years if it's a `Year` recurrence.
+    /// - `anchor`: The date from which the first occurrence is counted if it's a `Week` recurrence.
     ///
+    #[allow(clippy::too_many_arguments)]
     pub fn from_simple_recurrence(
         value: SimpleRecurrence,
         days: u8,
+        weeks: u8,
+        weekday: Weekday,
+        nth: i8,
         months: u8,
         years: u8,
+        anchor: NaiveDate,
     ) -> Self {
         match value {
             SimpleRecurrence::Day => Self::Day(days),
+            SimpleRecurrence::Week => Self::Week(weekday, weeks, anchor),
             SimpleRecurrence::Month => Self::Month(days, months),
+            SimpleRecurrence::MonthWeekday => Self::MonthWeekday(nth, weekday, months),
             SimpleRecurrence::Year => Self::Year(days, months, years),
         }
     }
@@ -99,7 +241,132 @@ impl Recurrence {
 //     }
 // }
 
-/// Returns the amount of times the recurrence occurs between the two given dates.
+/// An iterator over the dates on which a recurrence occurs between two dates (both included).
+/// It's created by [`Recurrence::occurrences`].
+#[derive(Debug, Clone)]
+pub struct Occurrences {
+    recurrence: Recurrence,
+    from: NaiveDate,
+    to: NaiveDate,
+    next: u32,
+}
+
+impl Iterator for Occurrences {
+    type Item = NaiveDate;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let date = self.recurrence.nth_candidate(self.from, self.next)?;
+            self.next += 1;
+
+            if date > self.to {
+                // Candidates are sorted, so there won't be any more occurrences
+                self.next -= 1;
+                return None;
+            }
+
+            if date >= self.from {
+                return Some(date);
+            }
+        }
+    }
+}
+
+impl Recurrence {
+    /// Returns the `n`-th date on which the recurrence could happen, counting from the period that
+    /// contains `from`. Only the first candidate can be before `from`.
+    fn nth_candidate(&self, from: NaiveDate, n: u32) -> Option<NaiveDate> {
+        match *self {
+            // There is no day of the month to anchor to, so it starts counting from `from`
+            Self::Day(each_days) => {
+                from.checked_add_days(Days::new((n as u64 + 1) * each_days.max(1) as u64))
+            }
+            Self::Week(weekday, each_weeks, anchor) => {
+                let step = 7 * each_weeks.max(1) as i64;
+
+                // The first occurrence is the first `weekday` on or after the anchor
+                let first = anchor.checked_add_days(Days::new(
+                    (weekday.num_days_from_monday() as i64
+                        - anchor.weekday().num_days_from_monday() as i64)
+                        .rem_euclid(7) as u64,
+                ))?;
+
+                // Skip the periods that ended before `from`
+                let skipped = if from > first {
+                    from.signed_duration_since(first).num_days() / step
+                } else {
+                    0
+                };
+
+                first.checked_add_days(Days::new(((skipped + n as i64) * step) as u64))
+            }
+            Self::Month(day, each_months) => {
+                let index =
+                    from.year() * 12 + from.month0() as i32 + n as i32 * each_months.max(1) as i32;
+
+                Some(clamp_to_month_index(index, day as u32))
+            }
+            Self::MonthWeekday(nth, weekday, each_months) => {
+                let index =
+                    from.year() * 12 + from.month0() as i32 + n as i32 * each_months.max(1) as i32;
+
+                Some(nth_weekday_of_month(
+                    index.div_euclid(12),
+                    index.rem_euclid(12) as u32 + 1,
+                    nth,
+                    weekday,
+                ))
+            }
+            Self::Year(day, month, each_years) => {
+                let year = from.year() + n as i32 * each_years.max(1) as i32;
+
+                Some(clamp_to_month(year, month as u32, day as u32))
+            }
+        }
+    }
+
+    /// Returns the dates on which the recurrence occurs between the two given dates (both
+    /// included). Days of the month that don't exist in a given month are moved to the last day of
+    /// that month (see [`clamp_to_month`]).
+    /// # Arguments
+    /// - `from`: The starting date.
+    /// - `to`: The target date.
+    /// # Returns
+    /// - An iterator over the dates, in chronological order.
+    /// # Examples
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use nix_bucks::Recurrence;
+    ///
+    /// fn main() {
+    ///    let start = NaiveDate::from_ymd_opt(2023, 1, 15).unwrap();
+    ///    let end = NaiveDate::from_ymd_opt(2023, 4, 30).unwrap();
+    ///
+    ///    let dates: Vec<NaiveDate> = Recurrence::Month(31, 1).occurrences(start, end).collect();
+    ///
+    ///    assert_eq!(
+    ///        dates,
+    ///        vec![
+    ///            NaiveDate::from_ymd_opt(2023, 1, 31).unwrap(),
+    ///            NaiveDate::from_ymd_opt(2023, 2, 28).unwrap(),
+    ///            NaiveDate::from_ymd_opt(2023, 3, 31).unwrap(),
+    ///            NaiveDate::from_ymd_opt(2023, 4, 30).unwrap(),
+    ///        ]
+    ///    );
+    /// }
+    /// ```
+    pub fn occurrences(&self, from: NaiveDate, to: NaiveDate) -> Occurrences {
+        Occurrences {
+            recurrence: *self,
+            from,
+            to,
+            next: 0,
+        }
+    }
+}
+
+/// Returns the amount of times the recurrence occurs between the two given dates (both included).
+/// It's the amount of dates returned by [`Recurrence::occurrences`].
 /// This function is cached: It will only run once for each value you give it. Then, it caches the
 /// result and returns it when you call it again with the same value.
 /// # Arguments
@@ -119,87 +386,14 @@ impl Recurrence {
 ///
 ///    let recurrence = Recurrence::Month(1, 1);
 ///    let times = times_until(recurrence, start, end);
-///    assert_eq!(times, 14);
+///    assert_eq!(times, 13);
 ///
 ///    println!("{}", times);
 /// }
 /// ```
 #[cached]
 pub fn times_until(recurrence: Recurrence, from: NaiveDate, to: NaiveDate) -> u32 {
-    match recurrence {
-        Recurrence::Day(each_days) => {
-            (to.signed_duration_since(from).num_days() as f32 / each_days as f32).trunc() as u32
-        }
-        Recurrence::Month(day, each_months) => {
-            // Count the amount of times the day "day" has passed since today to the target date
-            let mut start = from.clone().with_day(day as u32).unwrap();
-
-            let mut times: u32 = 0;
-
-            if start < from {
-                start = start
-                    .checked_add_months(Months::new(each_months as u32))
-                    .unwrap();
-            } else {
-                times += 1;
-            }
-
-            let target = to
-                .clone()
-                .with_day(day as u32)
-                .unwrap()
-                .checked_add_days(Days::new(1))
-                .unwrap();
-
-            while target > start {
-                times += 1;
-
-                start = start
-                    .checked_add_months(Months::new(each_months as u32))
-                    .unwrap();
-            }
-
-            times
-        }
-        Recurrence::Year(day, month, each_years) => {
-            // Count the amount of times the day "day" has passed since today to the target date
-            let mut start = from
-                .clone()
-                .with_day(day as u32)
-                .unwrap()
-                .with_month(month as u32)
-                .unwrap();
-
-            let mut times: u32 = 0;
-
-            if start < from {
-                start = start
-                    .checked_add_months(Months::new(each_years as u32 * 12))
-                    .unwrap();
-            } else {
-                times += 1;
-            }
-
-            let target = to
-                .clone()
-                .with_day(day as u32)
-                .unwrap()
-                .checked_add_days(Days::new(1))
-                .unwrap()
-                .with_month(month as u32)
-                .unwrap();
-
-            while target > start {
-                times += 1;
-
-                start = start
-                    .checked_add_months(Months::new(each_years as u32 * 12))
-                    .unwrap();
-            }
-
-            times
-        }
-    }
+    recurrence.occurrences(from, to).count() as u32
 }
 
 impl Recurrence {
@@ -211,7 +405,9 @@ impl Recurrence {
     pub fn to_simple_str(&self) -> &str {
         match self {
             Self::Day(_) => "Day",
+            Self::Week(_, _, _) => "Week",
             Self::Month(_, _) => "Month",
+            Self::MonthWeekday(_, _, _) => "MonthWeekday",
             Self::Year(_, _, _) => "Year",
         }
     }
@@ -224,12 +420,53 @@ impl Recurrence {
     pub fn to_lang_str(&self, lang: &str) -> String {
         match self {
             Self::Day(days) => t!("recurrence.days", days: &format!("{}", days), lang),
+            Self::Week(weekday, weeks, anchor) => {
+                t!("recurrence.weeks", anchor: &anchor.to_string(), weekday: &weekday_to_lang_str(*weekday, lang), weeks: &format!("{}", weeks), lang)
+            }
             Self::Month(day, months) => {
                 t!("recurrence.months", day: &format!("{}", day), months: &format!("{}", months), lang)
             }
+            Self::MonthWeekday(nth, weekday, months) => {
+                t!("recurrence.month_weekdays", months: &format!("{}", months), nth: &nth_to_lang_str(*nth, lang), weekday: &weekday_to_lang_str(*weekday, lang), lang)
+            }
             Self::Year(day, month, years) => {
                 t!("recurrence.years", day: &format!("{}", day), month: &format!("{}", month), years: &format!("{}", years), lang)
             }
         }
     }
 }
+
+/// Returns the name of the day of the week according to the language given.
+/// # Arguments
+/// - `weekday`: The day of the week.
+/// - `lang`: The language.
+/// # Returns
+/// - The name of the day of the week according to the language given.
+pub fn weekday_to_lang_str(weekday: Weekday, lang: &str) -> String {
+    match weekday {
+        Weekday::Mon => t!("weekday.monday", lang),
+        Weekday::Tue => t!("weekday.tuesday", lang),
+        Weekday::Wed => t!("weekday.wednesday", lang),
+        Weekday::Thu => t!("weekday.thursday", lang),
+        Weekday::Fri => t!("weekday.friday", lang),
+        Weekday::Sat => t!("weekday.saturday", lang),
+        Weekday::Sun => t!("weekday.sunday", lang),
+    }
+}
+
+/// Returns which day of the week of the month it is (e.g. "first" or "last") according to the
+/// language given.
+/// # Arguments
+/// - `nth`: Which day of the week of the month (1 is the first one, -1 is the last one).
+/// - `lang`: The language.
+/// # Returns
+/// - The string representation according to the language given.
+pub fn nth_to_lang_str(nth: i8, lang: &str) -> String {
+    match nth {
+        2 => t!("recurrence.nth.second", lang),
+        3 => t!("recurrence.nth.third", lang),
+        4 => t!("recurrence.nth.fourth", lang),
+        n if n < 0 => t!("recurrence.nth.last", lang),
+        _ => t!("recurrence.nth.first", lang),
+    }
+}
@@ -1,22 +1,97 @@
-use std::hash::Hash;
+use std::{collections::BTreeMap, hash::Hash};
 
-use chrono::{NaiveDate, Utc};
-use ordered_float::OrderedFloat;
+use chrono::{Days, NaiveDate, Utc, Weekday};
+use internationalization::t;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use super::{times_until, Recurrence, SimpleRecurrence};
+use super::{
+    escalate, BusinessDayRule, Estimate, HolidayCalendar, Labels, Money, Recurrence,
+    SimpleRecurrence, Split, TaxProfile, Uncertainty,
+};
+
+/// How many days an occurrence can be moved by a business day rule. Used to find the occurrences
+/// that are moved into the requested period.
+const ADJUSTMENT_MARGIN: u64 = 15;
+
+/// Information about a subscription that doesn't change its cost, to help reviewing and cancelling
+/// it. Every field is optional: an empty text or a notice period of zero days means it's not set.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct SubscriptionDetails {
+    /// The email of the account the subscription is registered with.
+    #[serde(default)]
+    pub email: String,
+    /// The page where the subscription is cancelled.
+    #[serde(default)]
+    pub cancel_url: String,
+    /// How many days before a payment the subscription has to be cancelled to avoid it.
+    #[serde(default)]
+    pub notice_days: u32,
+    #[serde(default)]
+    pub notes: String,
+}
+
+impl SubscriptionDetails {
+    /// Returns true if no field is set.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Returns true if any of the texts contains the query, ignoring the case.
+    /// # Arguments
+    /// - `query`: The text to search for, which must be in lowercase.
+    pub fn matches(&self, query: &str) -> bool {
+        [&self.email, &self.cancel_url, &self.notes]
+            .iter()
+            .any(|text| text.to_lowercase().contains(query))
+    }
+}
+
+/// Changes to a single occurrence of a subscription. The default value doesn't change anything.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct OccurrenceOverride {
+    /// The occurrence has already been paid. It's only informative.
+    #[serde(default)]
+    pub paid: bool,
+    /// The occurrence is not charged.
+    #[serde(default)]
+    pub skipped: bool,
+    /// The occurrence is charged this amount instead of the usual cost.
+    #[serde(default)]
+    pub amount: Option<Money>,
+    /// The occurrence is charged on this date instead.
+    #[serde(default)]
+    pub moved_to: Option<NaiveDate>,
+}
 
 /// A temporary subscription is a subscription that is not yet saved.
 /// It's used to create a new subscription.
 #[derive(Clone)]
 pub struct TmpSubscription {
     pub name: String,
-    pub cost: f32,
+    pub cost: f64,
     pub recurrence: SimpleRecurrence,
     pub days: u8,
+    pub weeks: u8,
+    pub weekday: Weekday,
+    pub nth: i8,
     pub months: u8,
     pub years: u8,
+    pub anchor: NaiveDate,
+    pub business_day_rule: BusinessDayRule,
+    pub has_start: bool,
+    pub start: NaiveDate,
+    pub has_end: bool,
+    pub end: NaiveDate,
+    pub category: Option<String>,
+    pub tags: String,
+    pub account: Option<Uuid>,
+    pub has_inflation: bool,
+    /// The annual inflation rate of the entry, as a percentage.
+    pub inflation: f64,
+    /// The tax profile of an income entered as gross.
+    pub tax_profile: Option<String>,
+    pub uncertainty: Option<Uncertainty>,
 }
 
 impl Default for TmpSubscription {
@@ -26,19 +101,66 @@ impl Default for TmpSubscription {
             cost: 10.0,
             recurrence: SimpleRecurrence::Month,
             days: 1,
+            weeks: 1,
+            weekday: Weekday::Mon,
+            nth: 1,
             months: 1,
             years: 1,
+            anchor: Utc::now().naive_utc().date(),
+            business_day_rule: BusinessDayRule::None,
+            has_start: false,
+            start: Utc::now().naive_utc().date(),
+            has_end: false,
+            end: Utc::now().naive_utc().date(),
+            category: None,
+            tags: String::new(),
+            account: None,
+            has_inflation: false,
+            inflation: 0.0,
+            tax_profile: None,
+            uncertainty: None,
         }
     }
 }
 
+impl TmpSubscription {
+    /// Returns true if the period is valid, i.e. it doesn't end before it starts.
+    pub fn is_period_valid(&self) -> bool {
+        !(self.has_start && self.has_end && self.end < self.start)
+    }
+}
+
 impl From<TmpSubscription> for Subscription {
     fn from(val: TmpSubscription) -> Self {
-        Subscription::new(
+        let mut subscription = Subscription::new(
             val.name.to_string(),
-            val.cost,
-            Recurrence::from_simple_recurrence(val.recurrence, val.days, val.months, val.years),
-        )
+            Money::from_f64(val.cost),
+            Recurrence::from_simple_recurrence(
+                val.recurrence,
+                val.days,
+                val.weeks,
+                val.weekday,
+                val.nth,
+                val.months,
+                val.years,
+                val.anchor,
+            ),
+        );
+
+        subscription.set_business_day_rule(val.business_day_rule);
+        subscription.set_period(
+            Some(val.start).filter(|_| val.has_start),
+            Some(val.end).filter(|_| val.has_end),
+        );
+        subscription.set_labels(Labels::new(val.category, &val.tags));
+        subscription.set_account(val.account);
+        subscription.set_inflation(
+            Some((val.inflation * 100.0).round().max(0.0) as u32).filter(|_| val.has_inflation),
+        );
+        subscription.set_tax_profile(val.tax_profile);
+        subscription.set_uncertainty(val.uncertainty);
+
+        subscription
     }
 }
 
@@ -47,8 +169,39 @@ impl From<TmpSubscription> for Subscription {
 pub struct Subscription {
     uuid: Uuid,
     name: String,
-    cost: OrderedFloat<f32>,
+    cost: Money,
     recurrence: Recurrence,
+    #[serde(default)]
+    business_day_rule: BusinessDayRule,
+    #[serde(default)]
+    start: Option<NaiveDate>,
+    #[serde(default)]
+    end: Option<NaiveDate>,
+    #[serde(default)]
+    price_changes: BTreeMap<NaiveDate, Money>,
+    #[serde(default)]
+    labels: Labels,
+    #[serde(default)]
+    account: Option<Uuid>,
+    #[serde(default)]
+    overrides: BTreeMap<NaiveDate, OccurrenceOverride>,
+    #[serde(default)]
+    inflation: Option<u32>,
+    /// The name of the tax profile of an income entered as gross. When it's set, the cost and the
+    /// price changes are gross amounts.
+    #[serde(default)]
+    tax_profile: Option<String>,
+    /// The tax profile itself, which is loaded from the config folder and set by the app.
+    #[serde(skip)]
+    tax: Option<TaxProfile>,
+    /// How much the cost can vary, for the entries with an uncertain amount.
+    #[serde(default)]
+    uncertainty: Option<Uncertainty>,
+    /// How the cost is shared with the household members.
+    #[serde(default)]
+    split: Split,
+    #[serde(default)]
+    details: SubscriptionDetails,
 }
 
 impl Subscription {
@@ -61,25 +214,38 @@ impl Subscription {
     /// - A new subscription.
     /// # Examples
     /// ```
-    /// use nix_bucks::{Subscription, Recurrence};
+    /// use nix_bucks::{Money, Subscription, Recurrence};
     /// use chrono::{Utc, NaiveDate};
     ///
     /// pub fn main() {
     ///    let subscription = Subscription::new(
     ///        String::from("My new subscription"),
-    ///        123.0,
+    ///        Money::from_cents(12300),
     ///        Recurrence::Month(1, 1)
     ///    );
     ///
     ///    println!("{:?}", subscription);
     /// }
     /// ```
-    pub fn new(name: String, cost: f32, recurrence: Recurrence) -> Self {
+    pub fn new(name: String, cost: Money, recurrence: Recurrence) -> Self {
         Self {
             uuid: Uuid::new_v4(),
             name,
-            cost: OrderedFloat(cost),
+            cost,
             recurrence,
+            business_day_rule: BusinessDayRule::None,
+            start: None,
+            end: None,
+            price_changes: BTreeMap::new(),
+            labels: Labels::default(),
+            account: None,
+            overrides: BTreeMap::new(),
+            inflation: None,
+            tax_profile: None,
+            tax: None,
+            uncertainty: None,
+            split: Split::default(),
+            details: SubscriptionDetails::default(),
         }
     }
 
@@ -93,9 +259,222 @@ impl Subscription {
         &self.name
     }
 
-    /// Returns the cost
-    pub fn cost(&self) -> f32 {
-        self.cost.0
+    /// Returns the cost, before any scheduled price change
+    pub fn cost(&self) -> Money {
+        self.cost
+    }
+
+    /// Returns the cost in effect on the given date, taking the scheduled price changes into
+    /// account. For an income entered as gross, it's the net amount.
+    /// # Arguments
+    /// - `date`: The date.
+    /// # Returns
+    /// - The cost in effect on that date.
+    pub fn cost_on(&self, date: NaiveDate) -> Money {
+        self.net(self.gross_on(date))
+    }
+
+    /// Returns the cost in effect on the given date as it was entered, before any tax, taking the
+    /// scheduled price changes into account.
+    /// # Arguments
+    /// - `date`: The date.
+    /// # Returns
+    /// - The cost in effect on that date.
+    pub fn gross_on(&self, date: NaiveDate) -> Money {
+        self.price_changes
+            .range(..=date)
+            .next_back()
+            .map_or(self.cost, |(_, cost)| *cost)
+    }
+
+    /// Returns the net amount of a gross payment, with the taxes of the whole year spread evenly
+    /// over its payments. Without a tax profile, the amount is returned as it is.
+    /// # Arguments
+    /// - `gross`: The gross amount of a payment.
+    /// # Returns
+    /// - The net amount of the payment.
+    pub fn net(&self, gross: Money) -> Money {
+        match &self.tax {
+            Some(tax) => {
+                let yearly = self.per_year(gross);
+
+                if yearly <= Money::ZERO {
+                    gross
+                } else {
+                    gross.mul_ratio(tax.yearly_net(yearly).cents(), yearly.cents())
+                }
+            }
+            None => gross,
+        }
+    }
+
+    /// Returns the name of the tax profile, if the income was entered as gross
+    pub fn tax_profile(&self) -> Option<&str> {
+        self.tax_profile.as_deref()
+    }
+
+    /// Sets the name of the tax profile.
+    /// # Arguments
+    /// - `tax_profile`: The name of the profile, or `None` if the amounts are net.
+    pub fn set_tax_profile(&mut self, tax_profile: Option<String>) {
+        self.tax_profile = tax_profile;
+    }
+
+    /// Returns the tax profile used to get the net amounts, if it was found
+    pub fn tax(&self) -> Option<&TaxProfile> {
+        self.tax.as_ref()
+    }
+
+    /// Sets the tax profile used to get the net amounts. It's not saved, only its name is.
+    /// # Arguments
+    /// - `tax`: The profile.
+    pub fn set_tax(&mut self, tax: Option<TaxProfile>) {
+        self.tax = tax;
+    }
+
+    /// Returns the scheduled price changes, sorted by date
+    pub fn price_changes(&self) -> &BTreeMap<NaiveDate, Money> {
+        &self.price_changes
+    }
+
+    /// Schedules a price change. It replaces any other change scheduled for the same date.
+    /// # Arguments
+    /// - `date`: The date from which the new cost is charged.
+    /// - `cost`: The new cost.
+    pub fn add_price_change(&mut self, date: NaiveDate, cost: Money) {
+        self.price_changes.insert(date, cost);
+    }
+
+    /// Removes the price change scheduled for the given date.
+    /// # Arguments
+    /// - `date`: The date of the price change.
+    pub fn remove_price_change(&mut self, date: NaiveDate) {
+        self.price_changes.remove(&date);
+    }
+
+    /// Returns the changes to single occurrences, by the date of the occurrence
+    pub fn overrides(&self) -> &BTreeMap<NaiveDate, OccurrenceOverride> {
+        &self.overrides
+    }
+
+    /// Returns the changes to the occurrence on the given date, if there are any.
+    pub fn override_on(&self, date: NaiveDate) -> OccurrenceOverride {
+        self.overrides.get(&date).copied().unwrap_or_default()
+    }
+
+    /// Changes a single occurrence. Setting the default value removes the change.
+    /// # Arguments
+    /// - `date`: The date of the occurrence, as returned by [`Subscription::occurrences`].
+    /// - `occurrence`: The changes to the occurrence.
+    pub fn set_override(&mut self, date: NaiveDate, occurrence: OccurrenceOverride) {
+        if occurrence == OccurrenceOverride::default() {
+            self.overrides.remove(&date);
+        } else {
+            self.overrides.insert(date, occurrence);
+        }
+    }
+
+    /// Returns the annual inflation rate of the entry in basis points (hundredths of a percent),
+    /// if it overrides the global one
+    pub fn inflation(&self) -> Option<u32> {
+        self.inflation
+    }
+
+    /// Sets the annual inflation rate of the entry.
+    /// # Arguments
+    /// - `inflation`: The rate in basis points (hundredths of a percent), or `None` to use the
+    ///   global one.
+    pub fn set_inflation(&mut self, inflation: Option<u32>) {
+        self.inflation = inflation;
+    }
+
+    /// Returns how much the cost can vary, if it's uncertain
+    pub fn uncertainty(&self) -> Option<&Uncertainty> {
+        self.uncertainty.as_ref()
+    }
+
+    /// Sets how much the cost can vary.
+    /// # Arguments
+    /// - `uncertainty`: The range and the probability, or `None` if the cost is certain.
+    pub fn set_uncertainty(&mut self, uncertainty: Option<Uncertainty>) {
+        self.uncertainty = uncertainty;
+    }
+
+    /// Returns how the cost is shared with the household members
+    pub fn split(&self) -> &Split {
+        &self.split
+    }
+
+    /// Sets how the cost is shared with the household members.
+    /// # Arguments
+    /// - `split`: The split.
+    pub fn set_split(&mut self, split: Split) {
+        self.split = split;
+    }
+
+    /// Returns the email, the cancel URL, the notice period and the notes
+    pub fn details(&self) -> &SubscriptionDetails {
+        &self.details
+    }
+
+    /// Sets the email, the cancel URL, the notice period and the notes.
+    /// # Arguments
+    /// - `details`: The details.
+    pub fn set_details(&mut self, details: SubscriptionDetails) {
+        self.details = details;
+    }
+
+    /// Returns true if the name, the category, the tags or any of the details contain the query,
+    /// ignoring the case. An empty query matches every subscription.
+    /// # Arguments
+    /// - `query`: The text to search for.
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.trim().to_lowercase();
+
+        query.is_empty()
+            || self.name.to_lowercase().contains(&query)
+            || self
+                .labels
+                .category
+                .as_ref()
+                .is_some_and(|category| category.to_lowercase().contains(&query))
+            || self
+                .labels
+                .tags
+                .iter()
+                .any(|tag| tag.to_lowercase().contains(&query))
+            || self.details.matches(&query)
+    }
+
+    /// Returns the last day to cancel the subscription, taking the notice period into account,
+    /// and the payment that is avoided by cancelling it on time.
+    /// # Arguments
+    /// - `from`: The date the subscription would be cancelled on.
+    /// - `holidays`: The bank holidays.
+    /// # Returns
+    /// - The deadline and the date of the payment, or `None` if there are no more payments.
+    pub fn cancel_by(
+        &self,
+        from: NaiveDate,
+        holidays: &HolidayCalendar,
+    ) -> Option<(NaiveDate, NaiveDate)> {
+        let notice = Days::new(self.details.notice_days as u64);
+        let payment = self.next_occurrence(from.checked_add_days(notice)?, holidays)?;
+
+        Some((payment.checked_sub_days(notice)?, payment))
+    }
+
+    /// Returns the estimated amount of a payment. The range of the entry is set for its base cost.
+    /// # Arguments
+    /// - `cost`: The cost of the payment.
+    /// - `estimate`: Which amount to return.
+    /// # Returns
+    /// - The estimated amount, which is `cost` if the cost is certain.
+    pub fn estimate(&self, cost: Money, estimate: Estimate) -> Money {
+        match &self.uncertainty {
+            Some(uncertainty) => uncertainty.estimate(cost, self.net(self.cost), estimate),
+            None => cost,
+        }
     }
 
     /// Returns the recurrence
@@ -103,40 +482,312 @@ impl Subscription {
         self.recurrence
     }
 
+    /// Returns the rule used to move payments that fall on non-working days
+    pub fn business_day_rule(&self) -> BusinessDayRule {
+        self.business_day_rule
+    }
+
+    /// Sets the rule used to move payments that fall on non-working days.
+    /// # Arguments
+    /// - `rule`: The new rule.
+    pub fn set_business_day_rule(&mut self, rule: BusinessDayRule) {
+        self.business_day_rule = rule;
+    }
+
+    /// Returns the category and the tags
+    pub fn labels(&self) -> &Labels {
+        &self.labels
+    }
+
+    /// Sets the category and the tags.
+    /// # Arguments
+    /// - `labels`: The new labels.
+    pub fn set_labels(&mut self, labels: Labels) {
+        self.labels = labels;
+    }
+
+    /// Returns the account the entry is charged to or paid into (`None` is the main account)
+    pub fn account(&self) -> Option<Uuid> {
+        self.account
+    }
+
+    /// Sets the account the entry is charged to or paid into.
+    /// # Arguments
+    /// - `account`: The account (`None` is the main account).
+    pub fn set_account(&mut self, account: Option<Uuid>) {
+        self.account = account;
+    }
+
+    /// Returns the date of the first payment, if there is one
+    pub fn start(&self) -> Option<NaiveDate> {
+        self.start
+    }
+
+    /// Returns the date after which there are no more payments, if there is one
+    pub fn end(&self) -> Option<NaiveDate> {
+        self.end
+    }
+
+    /// Sets the period in which the subscription is active. If the end is before the start, the
+    /// two dates are swapped, so that the subscription doesn't end before it starts.
+    /// # Arguments
+    /// - `start`: The date of the first payment. The recurrence is counted from this date, instead
+    ///   of from the date on which the payments are calculated.
+    /// - `end`: The date after which there are no more payments.
+    pub fn set_period(&mut self, start: Option<NaiveDate>, end: Option<NaiveDate>) {
+        match (start, end) {
+            (Some(start), Some(end)) if end < start => {
+                self.start = Some(end);
+                self.end = Some(start);
+            }
+            _ => {
+                self.start = start;
+                self.end = end;
+            }
+        }
+    }
+
+    /// Returns the period in which the subscription is active according to the language given.
+    /// # Arguments
+    /// - `lang`: The language.
+    /// # Returns
+    /// - The string representation according to the language given.
+    pub fn period_to_lang_str(&self, lang: &str) -> String {
+        match (self.start, self.end) {
+            (Some(start), Some(end)) => {
+                t!("subscription.period.between", end: &end.to_string(), start: &start.to_string(), lang)
+            }
+            (Some(start), None) => t!("subscription.period.from", start: &start.to_string(), lang),
+            (None, Some(end)) => t!("subscription.period.until", end: &end.to_string(), lang),
+            (None, None) => t!("subscription.period.always", lang),
+        }
+    }
+
+    /// Returns true if the given date is within the period in which the subscription is active.
+    pub fn is_active(&self, date: NaiveDate) -> bool {
+        self.start.is_none_or(|start| start <= date) && self.end.is_none_or(|end| date <= end)
+    }
+
+    /// Returns the dates on which the subscription is charged between the two given dates (both
+    /// included), after moving them to working days according to its business day rule.
+    /// Only the payments scheduled between the start and the end of the subscription are returned.
+    /// # Arguments
+    /// - `from`: The starting date.
+    /// - `to`: The target date.
+    /// - `holidays`: The bank holidays.
+    /// # Returns
+    /// - An iterator over the dates, in chronological order.
+    pub fn occurrences<'a>(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+        holidays: &'a HolidayCalendar,
+    ) -> impl Iterator<Item = NaiveDate> + 'a {
+        let rule = self.business_day_rule;
+        let (start, end) = (self.start, self.end);
+        let margin = to
+            .checked_add_days(Days::new(ADJUSTMENT_MARGIN))
+            .unwrap_or(NaiveDate::MAX);
+
+        let earliest = from
+            .checked_sub_days(Days::new(ADJUSTMENT_MARGIN))
+            .unwrap_or(NaiveDate::MIN);
+
+        // The recurrence is counted from the start date. `Day` recurrences don't include their
+        // anchor, so it's moved back one period to make the first payment on the start date.
+        // Without a start date they are counted from `from`, so no payment before it can be moved
+        // into the period. The other recurrences begin early enough to find those payments.
+        let anchor = match (start, self.recurrence) {
+            (Some(start), Recurrence::Day(each_days)) => start
+                .checked_sub_days(Days::new(each_days.max(1) as u64))
+                .unwrap_or(start),
+            (None, Recurrence::Day(_)) => from,
+            (Some(start), _) => start.max(earliest),
+            (None, _) => earliest,
+        };
+
+        self.recurrence
+            .occurrences(anchor, margin.min(end.unwrap_or(NaiveDate::MAX)))
+            .filter(move |date| start.is_none_or(|start| start <= *date))
+            .map(move |date| holidays.adjust(date, rule))
+            .filter(move |date| from <= *date && *date <= to)
+    }
+
+    /// Returns the next date on which the subscription is charged, starting from the given date
+    /// (included).
+    /// # Arguments
+    /// - `from`: The starting date.
+    /// - `holidays`: The bank holidays.
+    /// # Returns
+    /// - The next date, if there is any.
+    pub fn next_occurrence(
+        &self,
+        from: NaiveDate,
+        holidays: &HolidayCalendar,
+    ) -> Option<NaiveDate> {
+        self.occurrences(from, NaiveDate::MAX, holidays).next()
+    }
+
+    /// Returns the payments between the two given dates (both included), with the cost in effect
+    /// on each payment date. The changes to single occurrences are applied: skipped occurrences
+    /// are left out, and moved occurrences are charged on their new date.
+    /// # Arguments
+    /// - `from`: The starting date.
+    /// - `to`: The target date.
+    /// - `holidays`: The bank holidays.
+    /// # Returns
+    /// - An iterator over the dates and the amounts, in chronological order.
+    pub fn charges<'a>(
+        &'a self,
+        from: NaiveDate,
+        to: NaiveDate,
+        holidays: &'a HolidayCalendar,
+    ) -> impl Iterator<Item = (NaiveDate, Money)> + 'a {
+        let in_period = move |date: &NaiveDate| from <= *date && *date <= to;
+
+        let mut charges: Vec<(NaiveDate, Money)> = self
+            .occurrences(from, to, holidays)
+            .filter_map(|date| self.charge(date))
+            .filter(|(date, _)| in_period(date))
+            .collect();
+
+        // Occurrences moved into the period from outside of it. Changes left on dates that are
+        // not occurrences anymore, e.g. after the recurrence or the period was edited, are ignored.
+        charges.extend(
+            self.overrides
+                .keys()
+                .filter(|date| !in_period(date) && self.is_occurrence(**date, holidays))
+                .filter_map(|date| self.charge(*date))
+                .filter(|(date, _)| in_period(date)),
+        );
+        charges.sort();
+
+        charges.into_iter()
+    }
+
+    /// Returns the payments between the two given dates like [`Subscription::charges`], with the
+    /// costs escalated by inflation every whole year after `base`. The prices of the scheduled
+    /// price changes are escalated from the date of the change instead, since they are already in
+    /// the prices of that date.
+    /// # Arguments
+    /// - `from`: The starting date.
+    /// - `to`: The target date.
+    /// - `holidays`: The bank holidays.
+    /// - `inflation`: The global annual inflation rate in basis points, used when the entry
+    ///   doesn't have its own.
+    /// - `base`: The date of the prices of the costs (usually today).
+    /// # Returns
+    /// - An iterator over the dates and the escalated amounts, in chronological order.
+    pub fn escalated_charges<'a>(
+        &'a self,
+        from: NaiveDate,
+        to: NaiveDate,
+        holidays: &'a HolidayCalendar,
+        inflation: u32,
+        base: NaiveDate,
+    ) -> impl Iterator<Item = (NaiveDate, Money)> + 'a {
+        let rate = self.inflation.unwrap_or(inflation);
+
+        self.charges(from, to, holidays).map(move |(date, cost)| {
+            let priced_on = self
+                .price_changes
+                .range(..=date)
+                .next_back()
+                .map_or(base, |(changed, _)| (*changed).max(base));
+
+            (date, escalate(cost, rate, priced_on, date))
+        })
+    }
+
+    /// Returns true if the subscription is charged on the given date, before any change to single
+    /// occurrences. `Day` recurrences without a start date are counted from the date on which the
+    /// payments are calculated, so any date within the period can be one of their occurrences.
+    fn is_occurrence(&self, date: NaiveDate, holidays: &HolidayCalendar) -> bool {
+        match (self.start, self.recurrence) {
+            (None, Recurrence::Day(_)) => self.is_active(date),
+            _ => self.occurrences(date, date, holidays).any(|d| d == date),
+        }
+    }
+
+    /// Returns the date and the amount charged for the occurrence on the given date, or `None` if
+    /// it's skipped.
+    fn charge(&self, date: NaiveDate) -> Option<(NaiveDate, Money)> {
+        let occurrence = self.override_on(date);
+
+        if occurrence.skipped {
+            return None;
+        }
+
+        Some((
+            occurrence.moved_to.unwrap_or(date),
+            occurrence.amount.unwrap_or_else(|| self.cost_on(date)),
+        ))
+    }
+
     /// Calculates the cost from today until the given date.
     /// # Arguments
     /// - `to`: The date until the cost should be calculated.
+    /// - `holidays`: The bank holidays.
     /// # Returns
     /// - The cost from today until the given date.
-    pub fn cost_until(&self, to: NaiveDate) -> f32 {
-        let times = times_until(self.recurrence, Utc::now().naive_utc().date(), to);
+    pub fn cost_until(&self, to: NaiveDate, holidays: &HolidayCalendar) -> Money {
+        self.charges(Utc::now().naive_utc().date(), to, holidays)
+            .map(|(_, cost)| cost)
+            .sum()
+    }
 
-        self.cost.0 * times as f32
+    /// Calculates the estimated cost from today until the given date.
+    /// # Arguments
+    /// - `to`: The date until the cost should be calculated.
+    /// - `holidays`: The bank holidays.
+    /// - `estimate`: Which amount of each payment to use.
+    /// # Returns
+    /// - The estimated cost from today until the given date.
+    pub fn estimated_cost_until(
+        &self,
+        to: NaiveDate,
+        holidays: &HolidayCalendar,
+        estimate: Estimate,
+    ) -> Money {
+        self.charges(Utc::now().naive_utc().date(), to, holidays)
+            .map(|(_, cost)| self.estimate(cost, estimate))
+            .sum()
     }
 
-    /// Calculates the cost per year.
+    /// Calculates the cost per year, at the price in effect today.
     /// # Returns
-    /// - The cost per year.
-    pub fn cost_per_year(&self) -> f32 {
-        let times = match self.recurrence {
-            Recurrence::Day(each_days) => 365 / each_days as u32,
-            Recurrence::Month(_, each_months) => 12 / each_months as u32,
-            Recurrence::Year(_, _, each_years) => 1 / each_years as u32,
-        };
+    /// - The cost per year, rounded to the cent.
+    pub fn cost_per_year(&self) -> Money {
+        self.per_year(self.cost_on(Utc::now().naive_utc().date()))
+    }
 
-        self.cost.0 * times as f32
+    /// Returns the amount paid in a year if every payment is the given amount.
+    fn per_year(&self, cost: Money) -> Money {
+        match self.recurrence {
+            Recurrence::Day(each_days) => cost.mul_ratio(365, each_days.max(1) as i64),
+            Recurrence::Week(_, each_weeks, _) => cost.mul_ratio(365, 7 * each_weeks.max(1) as i64),
+            Recurrence::Month(_, each_months) | Recurrence::MonthWeekday(_, _, each_months) => {
+                cost.mul_ratio(12, each_months.max(1) as i64)
+            }
+            Recurrence::Year(_, _, each_years) => cost.mul_ratio(1, each_years.max(1) as i64),
+        }
     }
 
-    /// Calculates the cost per month.
+    /// Calculates the cost per month, at the price in effect today.
     /// # Returns
-    /// - The cost per month
-    pub fn cost_per_month(&self) -> f32 {
-        let times = match self.recurrence {
-            Recurrence::Day(each_days) => 30 / each_days as u32,
-            Recurrence::Month(_, each_months) => 1 / each_months as u32,
-            Recurrence::Year(_, _, each_years) => 1 / (each_years * 12) as u32,
-        };
+    /// - The average cost per month, rounded to the cent.
+    pub fn cost_per_month(&self) -> Money {
+        let cost = self.cost_on(Utc::now().naive_utc().date());
 
-        self.cost.0 * times as f32
+        match self.recurrence {
+            Recurrence::Day(each_days) => cost.mul_ratio(365, 12 * each_days.max(1) as i64),
+            Recurrence::Week(_, each_weeks, _) => {
+                cost.mul_ratio(365, 12 * 7 * each_weeks.max(1) as i64)
+            }
+            Recurrence::Month(_, each_months) | Recurrence::MonthWeekday(_, _, each_months) => {
+                cost.mul_ratio(1, each_months.max(1) as i64)
+            }
+            Recurrence::Year(_, _, each_years) => cost.mul_ratio(1, 12 * each_years.max(1) as i64),
+        }
     }
 }
@@ -0,0 +1,119 @@
+use serde::{Deserialize, Serialize};
+
+use super::{load_config, Money};
+
+/// A bracket of a progressive income tax: its rate applies to the part of the yearly income above
+/// `from`, up to the start of the next bracket.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TaxBracket {
+    /// The yearly income where the bracket starts.
+    pub from: Money,
+    /// The rate in basis points (hundredths of a percent), so 19% is 1900.
+    pub rate: u32,
+}
+
+/// The taxes and deductions taken from a gross income.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TaxProfile {
+    pub name: String,
+    /// The brackets of the income tax, in any order.
+    #[serde(default)]
+    pub brackets: Vec<TaxBracket>,
+    /// The flat social security contribution in basis points (hundredths of a percent). It's taken
+    /// from the gross income before the income tax, which only applies to the rest.
+    #[serde(default)]
+    pub social_security: u32,
+}
+
+impl TaxProfile {
+    /// Returns the income tax of a yearly taxable income.
+    /// # Arguments
+    /// - `base`: The yearly taxable income.
+    /// # Returns
+    /// - The income tax.
+    pub fn income_tax(&self, base: Money) -> Money {
+        let mut brackets = self.brackets.clone();
+        brackets.sort_by_key(|b| b.from);
+
+        brackets
+            .iter()
+            .enumerate()
+            .map(|(i, bracket)| {
+                let upper = brackets.get(i + 1).map_or(base, |next| next.from.min(base));
+                let taxable = (upper - bracket.from).max(Money::ZERO);
+
+                taxable.mul_ratio(bracket.rate as i64, 10000)
+            })
+            .sum()
+    }
+
+    /// Returns the yearly net income of a yearly gross income: the gross income minus the social
+    /// security contribution and the income tax.
+    /// # Arguments
+    /// - `gross`: The yearly gross income.
+    /// # Returns
+    /// - The yearly net income.
+    /// # Examples
+    /// ```
+    /// use nix_bucks::{Money, TaxBracket, TaxProfile};
+    ///
+    /// fn main() {
+    ///     let profile = TaxProfile {
+    ///         name: String::from("Example"),
+    ///         brackets: vec![
+    ///             TaxBracket { from: Money::ZERO, rate: 1000 },
+    ///             TaxBracket { from: Money::from_cents(2000000), rate: 3000 },
+    ///         ],
+    ///         social_security: 500,
+    ///     };
+    ///
+    ///     // 40000€ - 2000€ of social security leaves 38000€: 10% of 20000€ and 30% of 18000€
+    ///     assert_eq!(
+    ///         profile.yearly_net(Money::from_cents(4000000)),
+    ///         Money::from_cents(4000000 - 200000 - 200000 - 540000)
+    ///     );
+    /// }
+    /// ```
+    pub fn yearly_net(&self, gross: Money) -> Money {
+        let base = gross - gross.mul_ratio(self.social_security as i64, 10000);
+
+        base - self.income_tax(base)
+    }
+}
+
+/// The tax profiles the user can choose from.
+///
+/// They are stored in the config folder as "tax_profiles.json", which is a list of profiles, e.g.
+/// `[{"name": "Spain", "social_security": 635, "brackets": [{"from": 0, "rate": 1900}]}]`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct TaxProfiles {
+    profiles: Vec<TaxProfile>,
+}
+
+impl TaxProfiles {
+    /// Creates a list with the given profiles.
+    /// # Arguments
+    /// - `profiles`: The profiles.
+    /// # Returns
+    /// - A new list of profiles.
+    pub fn new(profiles: Vec<TaxProfile>) -> Self {
+        Self { profiles }
+    }
+
+    /// Loads the profiles from the "tax_profiles.json" file in the config folder. If the file does
+    /// not exist or can't be read, there are no profiles.
+    pub fn load() -> Self {
+        load_config("tax_profiles.json", "tax profiles")
+    }
+
+    /// Returns the profile with the given name, if there is one.
+    pub fn get(&self, name: &str) -> Option<&TaxProfile> {
+        self.profiles.iter().find(|p| p.name == name)
+    }
+
+    /// Returns the names of the profiles.
+    pub fn names(&self) -> Vec<String> {
+        self.profiles.iter().map(|p| p.name.clone()).collect()
+    }
+}
@@ -0,0 +1,116 @@
+use chrono::{NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::Money;
+
+/// A temporary transaction is a transaction that is not yet saved.
+/// It's used to create a new transaction.
+#[derive(Clone)]
+pub struct TmpTransaction {
+    pub description: String,
+    pub amount: f64,
+    pub income: bool,
+    pub date: NaiveDate,
+    pub account: Option<Uuid>,
+    pub entry: Option<Uuid>,
+}
+
+impl Default for TmpTransaction {
+    fn default() -> Self {
+        Self {
+            description: String::new(),
+            amount: 0.0,
+            income: false,
+            date: Utc::now().naive_utc().date(),
+            account: None,
+            entry: None,
+        }
+    }
+}
+
+impl From<TmpTransaction> for Transaction {
+    fn from(val: TmpTransaction) -> Self {
+        let amount = Money::from_f64(val.amount).abs();
+
+        Transaction::new(
+            val.description,
+            if val.income { amount } else { -amount },
+            val.date,
+            val.account,
+            val.entry,
+        )
+    }
+}
+
+/// A transaction is money that actually came in or went out of an account, as opposed to the
+/// planned amounts of the other entries.
+#[derive(Clone, Debug, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct Transaction {
+    uuid: Uuid,
+    pub description: String,
+    /// Positive if the money came in, negative if it went out.
+    pub amount: Money,
+    pub date: NaiveDate,
+    pub account: Option<Uuid>,
+    /// The planned entry (subscription, expense, income...) this transaction corresponds to.
+    pub entry: Option<Uuid>,
+}
+
+impl Transaction {
+    /// Create a new transaction.
+    /// # Arguments
+    /// - `description`: The description of the transaction.
+    /// - `amount`: The amount, positive if the money came in and negative if it went out.
+    /// - `date`: The date of the transaction.
+    /// - `account`: The account (`None` is the main account).
+    /// - `entry`: The planned entry this transaction corresponds to, if there is one.
+    /// # Returns
+    /// - A new transaction.
+    pub fn new(
+        description: String,
+        amount: Money,
+        date: NaiveDate,
+        account: Option<Uuid>,
+        entry: Option<Uuid>,
+    ) -> Self {
+        Self {
+            uuid: Uuid::new_v4(),
+            description,
+            amount,
+            date,
+            account,
+            entry,
+        }
+    }
+
+    /// Returns the uuid
+    pub fn uuid(&self) -> Uuid {
+        self.uuid
+    }
+
+    /// Returns the description
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// Returns the amount, positive if the money came in and negative if it went out
+    pub fn amount(&self) -> Money {
+        self.amount
+    }
+
+    /// Returns the date
+    pub fn date(&self) -> NaiveDate {
+        self.date
+    }
+
+    /// Returns the account (`None` is the main account)
+    pub fn account(&self) -> Option<Uuid> {
+        self.account
+    }
+
+    /// Returns the planned entry this transaction corresponds to, if there is one
+    pub fn entry(&self) -> Option<Uuid> {
+        self.entry
+    }
+}
@@ -0,0 +1,178 @@
+use chrono::{NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{Money, Occurrences, Recurrence};
+
+/// A temporary transfer is a transfer that is not yet saved.
+/// It's used to create a new transfer.
+#[derive(Clone)]
+pub struct TmpTransfer {
+    pub name: String,
+    pub amount: f64,
+    pub from: Option<Uuid>,
+    pub to: Option<Uuid>,
+    pub day: u8,
+    pub months: u8,
+}
+
+impl Default for TmpTransfer {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            amount: 0.0,
+            from: None,
+            to: None,
+            day: 1,
+            months: 1,
+        }
+    }
+}
+
+impl From<TmpTransfer> for Transfer {
+    fn from(val: TmpTransfer) -> Self {
+        Transfer::new(
+            val.name,
+            Money::from_f64(val.amount),
+            Recurrence::Month(val.day, val.months),
+            val.from,
+            val.to,
+        )
+    }
+}
+
+/// A recurring transfer of money between two accounts. It's neither an income nor an expense, it
+/// only moves money from one account to the other.
+#[derive(Clone, Debug, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct Transfer {
+    uuid: Uuid,
+    name: String,
+    amount: Money,
+    recurrence: Recurrence,
+    from: Option<Uuid>,
+    to: Option<Uuid>,
+}
+
+impl Transfer {
+    /// Create a new transfer.
+    /// # Arguments
+    /// - `name`: The name of the transfer.
+    /// - `amount`: The amount moved each time.
+    /// - `recurrence`: The recurrence of the transfer.
+    /// - `from`: The account the money is taken from (`None` is the main account).
+    /// - `to`: The account the money is moved to (`None` is the main account).
+    /// # Returns
+    /// - A new transfer.
+    pub fn new(
+        name: String,
+        amount: Money,
+        recurrence: Recurrence,
+        from: Option<Uuid>,
+        to: Option<Uuid>,
+    ) -> Self {
+        Self {
+            uuid: Uuid::new_v4(),
+            name,
+            amount,
+            recurrence,
+            from,
+            to,
+        }
+    }
+
+    /// Returns the uuid
+    pub fn uuid(&self) -> Uuid {
+        self.uuid
+    }
+
+    /// Returns the name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the amount moved each time
+    pub fn amount(&self) -> Money {
+        self.amount
+    }
+
+    /// Returns the recurrence
+    pub fn recurrence(&self) -> Recurrence {
+        self.recurrence
+    }
+
+    /// Returns the account the money is taken from
+    pub fn from(&self) -> Option<Uuid> {
+        self.from
+    }
+
+    /// Returns the account the money is moved to
+    pub fn to(&self) -> Option<Uuid> {
+        self.to
+    }
+
+    /// Returns the dates of the transfers between the two given dates (both included).
+    /// # Arguments
+    /// - `from`: The starting date.
+    /// - `to`: The target date.
+    /// # Returns
+    /// - An iterator over the dates, in chronological order.
+    pub fn occurrences(&self, from: NaiveDate, to: NaiveDate) -> Occurrences {
+        self.recurrence.occurrences(from, to)
+    }
+
+    /// Calculates how the balance of an account changes because of this transfer between the two
+    /// given dates (both included).
+    /// # Arguments
+    /// - `account`: The account (`None` is the main account).
+    /// - `from`: The starting date.
+    /// - `to`: The target date.
+    /// # Returns
+    /// - The money moved into the account, negative if it was moved out of it.
+    /// # Examples
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use nix_bucks::{Money, Recurrence, Transfer};
+    /// use uuid::Uuid;
+    ///
+    /// fn main() {
+    ///     let savings = Uuid::new_v4();
+    ///     let transfer = Transfer::new(
+    ///         String::from("Savings"),
+    ///         Money::from_cents(10000),
+    ///         Recurrence::Month(1, 1),
+    ///         None,
+    ///         Some(savings),
+    ///     );
+    ///
+    ///     let from = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+    ///     let to = NaiveDate::from_ymd_opt(2023, 3, 31).unwrap();
+    ///
+    ///     assert_eq!(transfer.net_between(Some(savings), from, to), Money::from_cents(30000));
+    ///     assert_eq!(transfer.net_between(None, from, to), Money::from_cents(-30000));
+    /// }
+    /// ```
+    pub fn net_between(&self, account: Option<Uuid>, from: NaiveDate, to: NaiveDate) -> Money {
+        if self.from == self.to || (account != self.from && account != self.to) {
+            return Money::ZERO;
+        }
+
+        let total = self.amount * self.occurrences(from, to).count() as i64;
+
+        if account == self.to {
+            total
+        } else {
+            -total
+        }
+    }
+
+    /// Calculates how the balance of an account changes because of this transfer from today until
+    /// the given date.
+    /// # Arguments
+    /// - `account`: The account (`None` is the main account).
+    /// - `to`: The target date.
+    /// # Returns
+    /// - The money moved into the account, negative if it was moved out of it.
+    pub fn net_until(&self, account: Option<Uuid>, to: NaiveDate) -> Money {
+        self.net_between(account, Utc::now().naive_utc().date(), to)
+    }
+}
@@ -0,0 +1,133 @@
+use internationalization::t;
+use serde::{Deserialize, Serialize};
+
+use super::Money;
+
+/// Which amount of an uncertain entry is used.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub enum Estimate {
+    /// The minimum amount, or nothing if the entry might not happen.
+    Low,
+    /// The expected amount, weighted by the probability of the entry.
+    Expected,
+    /// The maximum amount.
+    High,
+}
+
+/// A forecast where every uncertain entry turns out well, as expected or badly.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub enum Scenario {
+    Pessimistic,
+    Expected,
+    Optimistic,
+}
+
+impl Scenario {
+    /// Every scenario, in the order they are shown.
+    pub const ALL: [Scenario; 3] = [
+        Scenario::Pessimistic,
+        Scenario::Expected,
+        Scenario::Optimistic,
+    ];
+
+    /// Returns the amount of the incomes used in the scenario.
+    pub fn income(&self) -> Estimate {
+        match self {
+            Scenario::Pessimistic => Estimate::Low,
+            Scenario::Expected => Estimate::Expected,
+            Scenario::Optimistic => Estimate::High,
+        }
+    }
+
+    /// Returns the amount of the costs used in the scenario.
+    pub fn cost(&self) -> Estimate {
+        match self {
+            Scenario::Pessimistic => Estimate::High,
+            Scenario::Expected => Estimate::Expected,
+            Scenario::Optimistic => Estimate::Low,
+        }
+    }
+}
+
+/// How much an entry can vary from its amount, and how likely it is to happen at all.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct Uncertainty {
+    /// The minimum amount.
+    pub min: Money,
+    /// The maximum amount.
+    pub max: Money,
+    /// The probability of the entry happening, as a percentage.
+    #[serde(default = "certain")]
+    pub probability: u32,
+}
+
+/// The probability of an entry that always happens.
+fn certain() -> u32 {
+    100
+}
+
+impl Uncertainty {
+    /// Create a new uncertainty.
+    /// # Arguments
+    /// - `min`: The minimum amount.
+    /// - `max`: The maximum amount.
+    /// - `probability`: The probability of the entry happening, as a percentage.
+    pub fn new(min: Money, max: Money, probability: u32) -> Self {
+        Self {
+            min,
+            max,
+            probability: probability.min(100),
+        }
+    }
+
+    /// Returns the estimated amount of a payment. The minimum and the maximum are set for the
+    /// `base` amount, so they are scaled when the amount changes, e.g. with a price change.
+    /// # Arguments
+    /// - `amount`: The expected amount of the payment.
+    /// - `base`: The amount the minimum and the maximum were set for.
+    /// - `estimate`: Which amount to return.
+    /// # Returns
+    /// - The estimated amount. It's never above `amount` for [`Estimate::Low`] nor below it for
+    ///   [`Estimate::High`].
+    /// # Examples
+    /// ```
+    /// use nix_bucks::{Estimate, Money, Uncertainty};
+    ///
+    /// fn main() {
+    ///     let bill = Uncertainty::new(Money::from_cents(4000), Money::from_cents(9000), 100);
+    ///     let base = Money::from_cents(6000);
+    ///
+    ///     assert_eq!(bill.estimate(base, base, Estimate::Low), Money::from_cents(4000));
+    ///     assert_eq!(bill.estimate(base, base, Estimate::High), Money::from_cents(9000));
+    ///
+    ///     // After a price change to 120€, the range doubles too
+    ///     let new_price = Money::from_cents(12000);
+    ///     assert_eq!(bill.estimate(new_price, base, Estimate::High), Money::from_cents(18000));
+    /// }
+    /// ```
+    pub fn estimate(&self, amount: Money, base: Money, estimate: Estimate) -> Money {
+        let scale = |bound: Money| {
+            if base == Money::ZERO {
+                amount
+            } else {
+                amount.mul_ratio(bound.cents(), base.cents())
+            }
+        };
+
+        match estimate {
+            Estimate::Low if self.probability < 100 => Money::ZERO,
+            Estimate::Low => scale(self.min).min(amount),
+            Estimate::Expected => amount.mul_ratio(self.probability as i64, 100),
+            Estimate::High => scale(self.max).max(amount),
+        }
+    }
+}
+
+/// Returns the name of a scenario in the given language.
+pub fn scenario_to_lang_str(scenario: Scenario, lang: &str) -> String {
+    match scenario {
+        Scenario::Pessimistic => t!("scenario.pessimistic", lang),
+        Scenario::Expected => t!("scenario.expected", lang),
+        Scenario::Optimistic => t!("scenario.optimistic", lang),
+    }
+}
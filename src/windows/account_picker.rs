@@ -0,0 +1,39 @@
+use eframe::egui;
+use internationalization::t;
+use uuid::Uuid;
+
+use crate::Account;
+
+/// Draws a combo box to choose an account.
+/// # Arguments
+/// - `ui`: The [`egui::Ui`] to draw the combo box into.
+/// - `id`: The id of the combo box. It must be unique in the window.
+/// - `accounts`: The accounts the user can choose from, besides the main account.
+/// - `account`: The chosen account (`None` is the main account).
+/// - `lang`: The language.
+pub(crate) fn account_picker(
+    ui: &mut egui::Ui,
+    id: &str,
+    accounts: &[Account],
+    account: &mut Option<Uuid>,
+    lang: &str,
+) {
+    let main = t!("window.common.main_account", lang);
+
+    egui::ComboBox::from_id_source(id)
+        .selected_text(
+            accounts
+                .iter()
+                .find(|a| Some(a.uuid()) == *account)
+                .map_or(main.clone(), |a| a.name().to_string()),
+        )
+        .show_ui(ui, |ui| {
+            ui.style_mut().wrap = Some(false);
+            ui.set_min_width(60.0);
+            ui.selectable_value(account, None, main);
+
+            for a in accounts {
+                ui.selectable_value(account, Some(a.uuid()), a.name());
+            }
+        });
+}
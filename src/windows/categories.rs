@@ -0,0 +1,76 @@
+use eframe::egui;
+use internationalization::t;
+
+#[derive(Clone)]
+pub struct CategoriesWindow {
+    categories: Vec<String>,
+    new_category: String,
+}
+
+impl CategoriesWindow {
+    /// Creates the window to edit the list of categories.
+    /// # Arguments
+    /// - `categories`: The current categories.
+    pub fn new(categories: &[String]) -> Self {
+        Self {
+            categories: categories.to_vec(),
+            new_category: String::new(),
+        }
+    }
+
+    pub fn show(
+        &mut self,
+        ctx: &egui::Context,
+        show: &mut bool,
+        lang: &str,
+    ) -> Option<Vec<String>> {
+        let mut categories: Option<Vec<String>> = None;
+        egui::Window::new(t!("window.categories.title", lang))
+            .open(show)
+            .auto_sized()
+            .default_size([300.0, 400.0])
+            .show(ctx, |ui| {
+                ui.vertical_centered(|ui| {
+                    let mut removed: Option<usize> = None;
+
+                    for (i, category) in self.categories.iter().enumerate() {
+                        ui.horizontal(|ui| {
+                            ui.label(category);
+
+                            if ui.button(t!("app.button.delete", lang)).clicked() {
+                                removed = Some(i);
+                            }
+                        });
+                    }
+
+                    if let Some(i) = removed {
+                        self.categories.remove(i);
+                    }
+
+                    ui.separator();
+
+                    ui.horizontal(|ui| {
+                        ui.text_edit_singleline(&mut self.new_category);
+
+                        let name = self.new_category.trim().to_string();
+
+                        if ui.button(t!("window.common.add", lang)).clicked()
+                            && !name.is_empty()
+                            && !self.categories.contains(&name)
+                        {
+                            self.categories.push(name);
+                            self.new_category.clear();
+                        }
+                    });
+
+                    ui.separator();
+
+                    if ui.button(t!("window.common.save", lang)).clicked() {
+                        categories = Some(self.categories.clone());
+                    }
+                });
+            });
+
+        categories
+    }
+}
@@ -0,0 +1,196 @@
+use chrono::{NaiveDate, Utc};
+use eframe::egui;
+use egui_extras::{Column, TableBuilder};
+use internationalization::t;
+use uuid::Uuid;
+
+use crate::{plan_payoff, Loan, Money, PayoffPlan, PayoffStrategy};
+
+/// Returns the name of a strategy in the given language.
+fn strategy_to_lang_str(strategy: PayoffStrategy, lang: &str) -> String {
+    match strategy {
+        PayoffStrategy::Snowball => t!("window.debt_planner.snowball", lang),
+        PayoffStrategy::Avalanche => t!("window.debt_planner.avalanche", lang),
+        PayoffStrategy::Custom => t!("window.debt_planner.custom", lang),
+    }
+}
+
+/// Formats an optional payoff date.
+fn date_to_lang_str(date: Option<NaiveDate>, lang: &str) -> String {
+    date.map_or(t!("window.debt_planner.never", lang), |date| {
+        date.format("%m/%Y").to_string()
+    })
+}
+
+#[derive(Clone)]
+pub struct DebtPlannerWindow {
+    loans: Vec<Loan>,
+    extra: f64,
+    order: Vec<Uuid>,
+    plans: Vec<PayoffPlan>,
+}
+
+impl DebtPlannerWindow {
+    /// Creates the window to compare the strategies to pay down the given loans.
+    /// # Arguments
+    /// - `loans`: The loans. The ones already paid off are left out.
+    /// - `extra`: The extra amount to pay each month. Usually the monthly balance.
+    pub fn new(loans: &[Loan], extra: Money) -> Self {
+        let today = Utc::now().naive_utc().date();
+        let mut loans: Vec<Loan> = loans
+            .iter()
+            .filter(|l| !l.is_finished(today))
+            .cloned()
+            .collect();
+        loans.sort_by(|a, b| a.name().cmp(b.name()));
+
+        let mut window = Self {
+            order: loans.iter().map(|l| l.uuid()).collect(),
+            loans,
+            extra: extra.max(Money::ZERO).to_f64(),
+            plans: Vec::new(),
+        };
+        window.plan();
+
+        window
+    }
+
+    /// Simulates every strategy again.
+    fn plan(&mut self) {
+        let today = Utc::now().naive_utc().date();
+
+        self.plans = PayoffStrategy::ALL
+            .iter()
+            .map(|strategy| {
+                plan_payoff(
+                    &self.loans,
+                    Money::from_f64(self.extra),
+                    today,
+                    *strategy,
+                    &self.order,
+                )
+            })
+            .collect();
+    }
+
+    /// Returns the name of a loan.
+    fn loan_name(&self, uuid: Uuid) -> &str {
+        self.loans
+            .iter()
+            .find(|l| l.uuid() == uuid)
+            .map_or("", |l| l.name())
+    }
+
+    pub fn show(&mut self, ctx: &egui::Context, show: &mut bool, lang: &str) {
+        egui::Window::new(t!("window.debt_planner.title", lang))
+            .open(show)
+            .default_size([600.0, 400.0])
+            .show(ctx, |ui| {
+                let mut changed = false;
+
+                ui.horizontal(|ui| {
+                    ui.label(t!("window.debt_planner.extra", lang));
+
+                    changed |= ui
+                        .add(
+                            egui::DragValue::new(&mut self.extra)
+                                .speed(0.01)
+                                .max_decimals(2)
+                                .min_decimals(2)
+                                .clamp_range(0.0..=f64::MAX)
+                                .suffix(" €"),
+                        )
+                        .changed();
+                });
+                ui.separator();
+
+                ui.label(t!("window.debt_planner.custom_order", lang));
+
+                let mut swap: Option<(usize, usize)> = None;
+                for (i, uuid) in self.order.iter().enumerate() {
+                    ui.horizontal(|ui| {
+                        if ui.add_enabled(i > 0, egui::Button::new("⬆")).clicked() {
+                            swap = Some((i - 1, i));
+                        }
+                        if ui
+                            .add_enabled(i + 1 < self.order.len(), egui::Button::new("⬇"))
+                            .clicked()
+                        {
+                            swap = Some((i, i + 1));
+                        }
+
+                        ui.label(format!("{}. {}", i + 1, self.loan_name(*uuid)));
+                    });
+                }
+
+                if let Some((a, b)) = swap {
+                    self.order.swap(a, b);
+                    changed = true;
+                }
+
+                if changed {
+                    self.plan();
+                }
+                ui.separator();
+
+                TableBuilder::new(ui)
+                    .striped(true)
+                    .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+                    .column(Column::auto().at_least(150.0))
+                    .columns(Column::auto().at_least(100.0), self.plans.len())
+                    .header(20.0, |mut header| {
+                        header.col(|ui| {
+                            ui.spacing();
+                        });
+
+                        for plan in &self.plans {
+                            header.col(|ui| {
+                                ui.strong(strategy_to_lang_str(plan.strategy, lang));
+                            });
+                        }
+                    })
+                    .body(|mut body| {
+                        for loan in &self.loans {
+                            body.row(20.0, |mut row| {
+                                row.col(|ui| {
+                                    ui.label(loan.name());
+                                });
+
+                                for plan in &self.plans {
+                                    row.col(|ui| {
+                                        ui.label(date_to_lang_str(
+                                            plan.payoff_date(loan.uuid()),
+                                            lang,
+                                        ));
+                                    });
+                                }
+                            });
+                        }
+
+                        body.row(20.0, |mut row| {
+                            row.col(|ui| {
+                                ui.strong(t!("window.debt_planner.debt_free", lang));
+                            });
+
+                            for plan in &self.plans {
+                                row.col(|ui| {
+                                    ui.strong(date_to_lang_str(plan.debt_free(), lang));
+                                });
+                            }
+                        });
+
+                        body.row(20.0, |mut row| {
+                            row.col(|ui| {
+                                ui.strong(t!("window.debt_planner.interest", lang));
+                            });
+
+                            for plan in &self.plans {
+                                row.col(|ui| {
+                                    ui.strong(format!("{}€", plan.total_interest));
+                                });
+                            }
+                        });
+                    });
+            });
+    }
+}
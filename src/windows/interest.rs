@@ -0,0 +1,52 @@
+use eframe::egui;
+
+use crate::{compounding_to_lang_str, Compounding, InterestRate};
+
+/// Draws the fields to edit an interest rate: the annual rate and how often it's compounded.
+/// # Arguments
+/// - `ui`: The [`egui::Ui`] to draw the fields into.
+/// - `id`: The id of the combo box. It must be unique in the window.
+/// - `interest`: The interest rate to edit.
+/// - `lang`: The language.
+/// # Returns
+/// - True if the interest rate changed.
+pub(crate) fn interest_editor(
+    ui: &mut egui::Ui,
+    id: &str,
+    interest: &mut InterestRate,
+    lang: &str,
+) -> bool {
+    let prev = *interest;
+
+    ui.add(
+        egui::DragValue::from_get_set(|value| {
+            if let Some(value) = value {
+                interest.annual_rate = (value * 100.0).round().max(0.0) as u32;
+            }
+
+            interest.annual_rate as f64 / 100.0
+        })
+        .speed(0.01)
+        .max_decimals(2)
+        .min_decimals(2)
+        .clamp_range(0.0..=100.0)
+        .suffix(" %"),
+    );
+
+    egui::ComboBox::from_id_source(id)
+        .selected_text(compounding_to_lang_str(interest.compounding, lang))
+        .show_ui(ui, |ui| {
+            ui.style_mut().wrap = Some(false);
+            ui.set_min_width(60.0);
+
+            for compounding in Compounding::ALL {
+                ui.selectable_value(
+                    &mut interest.compounding,
+                    compounding,
+                    compounding_to_lang_str(compounding, lang),
+                );
+            }
+        });
+
+    prev != *interest
+}
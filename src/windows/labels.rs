@@ -0,0 +1,41 @@
+use eframe::egui;
+use internationalization::t;
+
+/// Draws the category picker and the tags field shared by all the new-entry windows.
+/// # Arguments
+/// - `ui`: The [`egui::Ui`] to draw the fields into.
+/// - `categories`: The categories the user can choose from.
+/// - `category`: The chosen category.
+/// - `tags`: The tags, separated by commas.
+/// - `lang`: The language.
+pub(crate) fn labels_editor(
+    ui: &mut egui::Ui,
+    categories: &[String],
+    category: &mut Option<String>,
+    tags: &mut String,
+    lang: &str,
+) {
+    ui.vertical(|ui| {
+        ui.label(t!("window.common.category", lang));
+
+        egui::ComboBox::from_id_source("Category")
+            .selected_text(
+                category
+                    .clone()
+                    .unwrap_or_else(|| t!("window.common.no_category", lang)),
+            )
+            .show_ui(ui, |ui| {
+                ui.style_mut().wrap = Some(false);
+                ui.set_min_width(60.0);
+                ui.selectable_value(category, None, t!("window.common.no_category", lang));
+
+                for c in categories {
+                    ui.selectable_value(category, Some(c.clone()), c);
+                }
+            });
+
+        ui.label(t!("window.common.tags", lang));
+
+        ui.text_edit_singleline(tags);
+    });
+}
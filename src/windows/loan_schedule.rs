@@ -0,0 +1,77 @@
+use eframe::egui;
+use egui_extras::{Column, TableBuilder};
+use internationalization::t;
+
+use crate::{Loan, LoanPayment};
+
+#[derive(Clone)]
+pub struct LoanScheduleWindow {
+    name: String,
+    schedule: Vec<LoanPayment>,
+}
+
+impl LoanScheduleWindow {
+    /// Creates the window with the amortisation schedule of the given loan.
+    /// # Arguments
+    /// - `loan`: The loan.
+    pub fn new(loan: &Loan) -> Self {
+        Self {
+            name: loan.name().to_string(),
+            schedule: loan.schedule(),
+        }
+    }
+
+    pub fn show(&mut self, ctx: &egui::Context, show: &mut bool, lang: &str) {
+        egui::Window::new(t!("window.loan_schedule.title", name: &self.name, lang))
+            .open(show)
+            .default_size([600.0, 400.0])
+            .show(ctx, |ui| {
+                TableBuilder::new(ui)
+                    .striped(true)
+                    .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+                    .column(Column::auto().at_least(100.0))
+                    .column(Column::auto().at_least(100.0))
+                    .column(Column::auto().at_least(100.0))
+                    .column(Column::auto().at_least(100.0))
+                    .column(Column::remainder())
+                    .header(20.0, |mut header| {
+                        header.col(|ui| {
+                            ui.strong(t!("app.table.title.date", lang));
+                        });
+                        header.col(|ui| {
+                            ui.strong(t!("window.loan_schedule.payment", lang));
+                        });
+                        header.col(|ui| {
+                            ui.strong(t!("window.loan_schedule.interest", lang));
+                        });
+                        header.col(|ui| {
+                            ui.strong(t!("window.loan_schedule.principal", lang));
+                        });
+                        header.col(|ui| {
+                            ui.strong(t!("window.loan_schedule.balance", lang));
+                        });
+                    })
+                    .body(|body| {
+                        body.rows(20.0, self.schedule.len(), |index, mut row| {
+                            let payment = self.schedule[index];
+
+                            row.col(|ui| {
+                                ui.label(payment.date.format("%d/%m/%Y").to_string());
+                            });
+                            row.col(|ui| {
+                                ui.label(format!("{}€", payment.payment));
+                            });
+                            row.col(|ui| {
+                                ui.label(format!("{}€", payment.interest));
+                            });
+                            row.col(|ui| {
+                                ui.label(format!("{}€", payment.principal));
+                            });
+                            row.col(|ui| {
+                                ui.label(format!("{}€", payment.balance));
+                            });
+                        });
+                    });
+            });
+    }
+}
@@ -1,9 +1,47 @@
+mod account_picker;
+mod categories;
+mod debt_planner;
+mod interest;
+mod labels;
+mod loan_schedule;
+mod new_account;
+mod new_budget;
 mod new_expense;
+mod new_goal;
 mod new_income;
+mod new_installment;
+mod new_loan;
+mod new_member;
+mod new_price_change;
 mod new_punctual_income;
 mod new_subscription;
+mod new_transaction;
+mod new_transfer;
+mod occurrences;
+mod split;
+mod subscription_details;
+mod uncertainty;
 
+pub(crate) use account_picker::account_picker;
+pub use categories::CategoriesWindow;
+pub use debt_planner::DebtPlannerWindow;
+pub(crate) use interest::interest_editor;
+pub(crate) use labels::labels_editor;
+pub use loan_schedule::LoanScheduleWindow;
+pub use new_account::NewAccountWindow;
+pub use new_budget::NewBudgetWindow;
 pub use new_expense::NewExpenseWindow;
+pub use new_goal::NewGoalWindow;
 pub use new_income::NewIncomeWindow;
+pub use new_installment::NewInstallmentWindow;
+pub use new_loan::NewLoanWindow;
+pub use new_member::NewMemberWindow;
+pub use new_price_change::NewPriceChangeWindow;
 pub use new_punctual_income::NewPunctualIncomeWindow;
 pub use new_subscription::NewSubscriptionWindow;
+pub use new_transaction::NewTransactionWindow;
+pub use new_transfer::NewTransferWindow;
+pub use occurrences::OccurrencesWindow;
+pub use split::SplitWindow;
+pub use subscription_details::SubscriptionDetailsWindow;
+pub(crate) use uncertainty::uncertainty_editor;
@@ -0,0 +1,62 @@
+use eframe::egui;
+use internationalization::t;
+
+use crate::{windows::interest_editor, Account, InterestRate, Money};
+
+#[derive(Default, Clone)]
+pub struct NewAccountWindow {
+    name: String,
+    balance: f64,
+    interest: InterestRate,
+}
+
+impl NewAccountWindow {
+    pub fn show(&mut self, ctx: &egui::Context, show: &mut bool, lang: &str) -> Option<Account> {
+        let mut account: Option<Account> = None;
+        egui::Window::new(t!("window.account.title", lang))
+            .open(show)
+            .auto_sized()
+            .default_size([600.0, 200.0])
+            .show(ctx, |ui| {
+                ui.vertical_centered(|ui| {
+                    ui.horizontal_centered(|ui| {
+                        ui.vertical(|ui| {
+                            ui.label(t!("window.account.name", lang));
+
+                            ui.text_edit_singleline(&mut self.name);
+                        });
+
+                        ui.vertical(|ui| {
+                            ui.label(t!("window.account.balance", lang));
+
+                            ui.add(
+                                egui::DragValue::new(&mut self.balance)
+                                    .speed(0.01)
+                                    .max_decimals(2)
+                                    .min_decimals(2)
+                                    .suffix(" €"),
+                            );
+                        });
+                    });
+                    ui.separator();
+
+                    ui.horizontal(|ui| {
+                        ui.label(t!("window.account.interest", lang));
+
+                        interest_editor(ui, "Compounding", &mut self.interest, lang);
+                    });
+                    ui.separator();
+
+                    if ui.button(t!("window.common.add", lang)).clicked() {
+                        let mut new_account =
+                            Account::new(self.name.clone(), Money::from_f64(self.balance));
+                        new_account.interest = self.interest;
+
+                        account = Some(new_account);
+                    }
+                });
+            });
+
+        account
+    }
+}
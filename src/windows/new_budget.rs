@@ -0,0 +1,75 @@
+use eframe::egui;
+use internationalization::t;
+
+use crate::{Budget, Money};
+
+#[derive(Default, Clone)]
+pub struct NewBudgetWindow {
+    category: Option<String>,
+    limit: f64,
+    categories: Vec<String>,
+}
+
+impl NewBudgetWindow {
+    /// Creates the window.
+    /// # Arguments
+    /// - `categories`: The categories the user can choose from.
+    pub fn new(categories: &[String]) -> Self {
+        Self {
+            category: categories.first().cloned(),
+            limit: 0.0,
+            categories: categories.to_vec(),
+        }
+    }
+
+    pub fn show(&mut self, ctx: &egui::Context, show: &mut bool, lang: &str) -> Option<Budget> {
+        let mut budget: Option<Budget> = None;
+        egui::Window::new(t!("window.budget.title", lang))
+            .open(show)
+            .auto_sized()
+            .default_size([600.0, 200.0])
+            .show(ctx, |ui| {
+                ui.vertical_centered(|ui| {
+                    ui.horizontal_centered(|ui| {
+                        ui.vertical(|ui| {
+                            ui.label(t!("window.common.category", lang));
+
+                            egui::ComboBox::from_id_source("Budget category")
+                                .selected_text(self.category.clone().unwrap_or_default())
+                                .show_ui(ui, |ui| {
+                                    ui.style_mut().wrap = Some(false);
+                                    ui.set_min_width(60.0);
+
+                                    for c in &self.categories {
+                                        ui.selectable_value(&mut self.category, Some(c.clone()), c);
+                                    }
+                                });
+                        });
+
+                        ui.vertical(|ui| {
+                            ui.label(t!("window.budget.limit", lang));
+
+                            ui.add(
+                                egui::DragValue::new(&mut self.limit)
+                                    .speed(0.01)
+                                    .max_decimals(2)
+                                    .min_decimals(2)
+                                    .clamp_range(0.0..=f64::MAX)
+                                    .suffix(" €"),
+                            );
+                        });
+                    });
+                    ui.separator();
+
+                    if let Some(category) = &self.category {
+                        if ui.button(t!("window.common.add", lang)).clicked() {
+                            budget =
+                                Some(Budget::new(category.clone(), Money::from_f64(self.limit)));
+                        }
+                    }
+                });
+            });
+
+        budget
+    }
+}
@@ -2,13 +2,24 @@ use chrono::{NaiveDate, Utc};
 use eframe::egui;
 use internationalization::t;
 
-use crate::FixedExpense;
+use uuid::Uuid;
+
+use crate::{
+    windows::{account_picker, labels_editor, uncertainty_editor},
+    Account, FixedExpense, Labels, Money, Uncertainty,
+};
 
 #[derive(Clone)]
 pub struct NewExpenseWindow {
     name: String,
-    cost: f32,
+    cost: f64,
+    uncertainty: Option<Uncertainty>,
     date: NaiveDate,
+    category: Option<String>,
+    tags: String,
+    account: Option<Uuid>,
+    categories: Vec<String>,
+    accounts: Vec<Account>,
 }
 
 impl Default for NewExpenseWindow {
@@ -16,12 +27,30 @@ impl Default for NewExpenseWindow {
         Self {
             name: String::new(),
             cost: 0.0,
+            uncertainty: None,
             date: Utc::now().naive_utc().date(),
+            category: None,
+            tags: String::new(),
+            account: None,
+            categories: Vec::new(),
+            accounts: Vec::new(),
         }
     }
 }
 
 impl NewExpenseWindow {
+    /// Creates the window.
+    /// # Arguments
+    /// - `categories`: The categories the user can choose from.
+    /// - `accounts`: The accounts the user can choose from, besides the main account.
+    pub fn new(categories: &[String], accounts: &[Account]) -> Self {
+        Self {
+            categories: categories.to_vec(),
+            accounts: accounts.to_vec(),
+            ..Default::default()
+        }
+    }
+
     pub fn show(
         &mut self,
         ctx: &egui::Context,
@@ -52,6 +81,8 @@ impl NewExpenseWindow {
                                     .min_decimals(2)
                                     .suffix(" €"),
                             );
+
+                            uncertainty_editor(ui, &mut self.uncertainty, self.cost, lang);
                         });
 
                         ui.vertical(|ui| {
@@ -62,8 +93,31 @@ impl NewExpenseWindow {
                     });
                     ui.separator();
 
+                    labels_editor(
+                        ui,
+                        &self.categories,
+                        &mut self.category,
+                        &mut self.tags,
+                        lang,
+                    );
+                    ui.horizontal(|ui| {
+                        ui.label(t!("window.common.account", lang));
+
+                        account_picker(ui, "Account", &self.accounts, &mut self.account, lang);
+                    });
+                    ui.separator();
+
                     if ui.button(t!("window.common.add", lang)).clicked() {
-                        subs = Some(FixedExpense::new(self.name.clone(), self.cost, self.date));
+                        let mut expense = FixedExpense::new(
+                            self.name.clone(),
+                            Money::from_f64(self.cost),
+                            self.date,
+                        );
+                        expense.labels = Labels::new(self.category.clone(), &self.tags);
+                        expense.account = self.account;
+                        expense.uncertainty = self.uncertainty;
+
+                        subs = Some(expense);
                     }
                 });
             });
@@ -0,0 +1,89 @@
+use chrono::{Months, NaiveDate, Utc};
+use eframe::egui;
+use internationalization::t;
+use uuid::Uuid;
+
+use crate::{windows::account_picker, Account, Goal, Money};
+
+#[derive(Clone)]
+pub struct NewGoalWindow {
+    name: String,
+    target: f64,
+    date: NaiveDate,
+    account: Option<Uuid>,
+    accounts: Vec<Account>,
+}
+
+impl NewGoalWindow {
+    /// Creates the window.
+    /// # Arguments
+    /// - `accounts`: The accounts the user can choose from, besides the main account.
+    pub fn new(accounts: &[Account]) -> Self {
+        let today = Utc::now().naive_utc().date();
+
+        Self {
+            name: String::new(),
+            target: 0.0,
+            date: today.checked_add_months(Months::new(12)).unwrap_or(today),
+            account: None,
+            accounts: accounts.to_vec(),
+        }
+    }
+
+    pub fn show(&mut self, ctx: &egui::Context, show: &mut bool, lang: &str) -> Option<Goal> {
+        let mut goal: Option<Goal> = None;
+        egui::Window::new(t!("window.goal.title", lang))
+            .open(show)
+            .auto_sized()
+            .default_size([600.0, 200.0])
+            .show(ctx, |ui| {
+                ui.vertical_centered(|ui| {
+                    ui.horizontal_centered(|ui| {
+                        ui.vertical(|ui| {
+                            ui.label(t!("window.common.concept", lang));
+
+                            ui.text_edit_singleline(&mut self.name);
+                        });
+
+                        ui.vertical(|ui| {
+                            ui.label(t!("window.goal.target", lang));
+
+                            ui.add(
+                                egui::DragValue::new(&mut self.target)
+                                    .speed(0.01)
+                                    .max_decimals(2)
+                                    .min_decimals(2)
+                                    .clamp_range(0.0..=f64::MAX)
+                                    .suffix(" €"),
+                            );
+                        });
+
+                        ui.vertical(|ui| {
+                            ui.label(t!("window.goal.date", lang));
+
+                            ui.add(egui_extras::DatePickerButton::new(&mut self.date));
+                        });
+                    });
+                    ui.separator();
+
+                    ui.horizontal(|ui| {
+                        ui.label(t!("window.common.account", lang));
+
+                        account_picker(ui, "Account", &self.accounts, &mut self.account, lang);
+                    });
+                    ui.separator();
+
+                    if ui.button(t!("window.common.add", lang)).clicked() {
+                        goal = Some(Goal::new(
+                            self.name.clone(),
+                            Money::from_f64(self.target),
+                            self.date,
+                            self.account,
+                        ));
+                    }
+                });
+            });
+
+        goal
+    }
+}
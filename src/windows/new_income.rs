@@ -1,14 +1,37 @@
 use eframe::egui;
 use internationalization::t;
 
-use crate::{SimpleRecurrence, Subscription, TmpSubscription};
+use chrono::Weekday;
+
+use crate::{
+    nth_to_lang_str, weekday_to_lang_str,
+    windows::{account_picker, labels_editor, uncertainty_editor},
+    Account, BusinessDayRule, SimpleRecurrence, Subscription, TmpSubscription,
+};
 
 #[derive(Default, Clone)]
 pub struct NewIncomeWindow {
     tmp_subscription: TmpSubscription,
+    categories: Vec<String>,
+    accounts: Vec<Account>,
+    tax_profiles: Vec<String>,
 }
 
 impl NewIncomeWindow {
+    /// Creates the window.
+    /// # Arguments
+    /// - `categories`: The categories the user can choose from.
+    /// - `accounts`: The accounts the user can choose from, besides the main account.
+    /// - `tax_profiles`: The names of the tax profiles the user can choose from.
+    pub fn new(categories: &[String], accounts: &[Account], tax_profiles: &[String]) -> Self {
+        Self {
+            tmp_subscription: TmpSubscription::default(),
+            categories: categories.to_vec(),
+            accounts: accounts.to_vec(),
+            tax_profiles: tax_profiles.to_vec(),
+        }
+    }
+
     pub fn show(
         &mut self,
         ctx: &egui::Context,
@@ -30,7 +53,11 @@ impl NewIncomeWindow {
                         });
 
                         ui.vertical(|ui| {
-                            ui.label(t!("window.common.cost", lang));
+                            if self.tmp_subscription.tax_profile.is_some() {
+                                ui.label(t!("window.income.gross", lang));
+                            } else {
+                                ui.label(t!("window.common.cost", lang));
+                            }
 
                             ui.add(
                                 egui::DragValue::new(&mut self.tmp_subscription.cost)
@@ -39,6 +66,40 @@ impl NewIncomeWindow {
                                     .min_decimals(2)
                                     .suffix(" €"),
                             );
+
+                            uncertainty_editor(
+                                ui,
+                                &mut self.tmp_subscription.uncertainty,
+                                self.tmp_subscription.cost,
+                                lang,
+                            );
+
+                            egui::ComboBox::from_id_source("Tax profile")
+                                .selected_text(
+                                    self.tmp_subscription
+                                        .tax_profile
+                                        .clone()
+                                        .unwrap_or(t!("window.income.net", lang)),
+                                )
+                                .show_ui(ui, |ui| {
+                                    ui.style_mut().wrap = Some(false);
+                                    ui.set_min_width(60.0);
+                                    ui.selectable_value(
+                                        &mut self.tmp_subscription.tax_profile,
+                                        None,
+                                        t!("window.income.net", lang),
+                                    );
+
+                                    for profile in &self.tax_profiles {
+                                        ui.selectable_value(
+                                            &mut self.tmp_subscription.tax_profile,
+                                            Some(profile.clone()),
+                                            profile,
+                                        );
+                                    }
+                                })
+                                .response
+                                .on_hover_text(t!("window.income.tax_profiles_hint", lang));
                         });
 
                         ui.vertical(|ui| {
@@ -54,11 +115,21 @@ impl NewIncomeWindow {
                                         SimpleRecurrence::Day,
                                         t!("window.common.daily", lang),
                                     );
+                                    ui.selectable_value(
+                                        &mut self.tmp_subscription.recurrence,
+                                        SimpleRecurrence::Week,
+                                        t!("window.common.weekly", lang),
+                                    );
                                     ui.selectable_value(
                                         &mut self.tmp_subscription.recurrence,
                                         SimpleRecurrence::Month,
                                         t!("window.common.monthly", lang),
                                     );
+                                    ui.selectable_value(
+                                        &mut self.tmp_subscription.recurrence,
+                                        SimpleRecurrence::MonthWeekday,
+                                        t!("window.common.monthly_weekday", lang),
+                                    );
                                     ui.selectable_value(
                                         &mut self.tmp_subscription.recurrence,
                                         SimpleRecurrence::Year,
@@ -78,6 +149,47 @@ impl NewIncomeWindow {
                                                 .suffix(t!("window.common.days", lang)),
                                         );
                                     }
+                                    SimpleRecurrence::Week => {
+                                        egui::ComboBox::from_id_source("Weekday")
+                                            .selected_text(weekday_to_lang_str(
+                                                self.tmp_subscription.weekday,
+                                                lang,
+                                            ))
+                                            .show_ui(ui, |ui| {
+                                                for weekday in [
+                                                    Weekday::Mon,
+                                                    Weekday::Tue,
+                                                    Weekday::Wed,
+                                                    Weekday::Thu,
+                                                    Weekday::Fri,
+                                                    Weekday::Sat,
+                                                    Weekday::Sun,
+                                                ] {
+                                                    ui.selectable_value(
+                                                        &mut self.tmp_subscription.weekday,
+                                                        weekday,
+                                                        weekday_to_lang_str(weekday, lang),
+                                                    );
+                                                }
+                                            });
+                                        ui.add(
+                                            egui::DragValue::new(&mut self.tmp_subscription.weeks)
+                                                .speed(1.0)
+                                                .max_decimals(0)
+                                                .clamp_range(1..=52)
+                                                .prefix(t!("window.common.every", lang))
+                                                .suffix(t!("window.common.weeks", lang)),
+                                        );
+                                        ui.horizontal(|ui| {
+                                            ui.label(t!("window.common.starting", lang));
+                                            ui.add(
+                                                egui_extras::DatePickerButton::new(
+                                                    &mut self.tmp_subscription.anchor,
+                                                )
+                                                .id_source("Anchor"),
+                                            );
+                                        });
+                                    }
                                     SimpleRecurrence::Month => {
                                         ui.add(
                                             egui::DragValue::new(&mut self.tmp_subscription.days)
@@ -96,6 +208,54 @@ impl NewIncomeWindow {
                                                 .suffix(t!("window.common.months", lang)),
                                         );
                                     }
+                                    SimpleRecurrence::MonthWeekday => {
+                                        ui.horizontal(|ui| {
+                                            egui::ComboBox::from_id_source("Week of the month")
+                                                .selected_text(nth_to_lang_str(
+                                                    self.tmp_subscription.nth,
+                                                    lang,
+                                                ))
+                                                .show_ui(ui, |ui| {
+                                                    for nth in [1, 2, 3, 4, -1] {
+                                                        ui.selectable_value(
+                                                            &mut self.tmp_subscription.nth,
+                                                            nth,
+                                                            nth_to_lang_str(nth, lang),
+                                                        );
+                                                    }
+                                                });
+                                            egui::ComboBox::from_id_source("Weekday")
+                                                .selected_text(weekday_to_lang_str(
+                                                    self.tmp_subscription.weekday,
+                                                    lang,
+                                                ))
+                                                .show_ui(ui, |ui| {
+                                                    for weekday in [
+                                                        Weekday::Mon,
+                                                        Weekday::Tue,
+                                                        Weekday::Wed,
+                                                        Weekday::Thu,
+                                                        Weekday::Fri,
+                                                        Weekday::Sat,
+                                                        Weekday::Sun,
+                                                    ] {
+                                                        ui.selectable_value(
+                                                            &mut self.tmp_subscription.weekday,
+                                                            weekday,
+                                                            weekday_to_lang_str(weekday, lang),
+                                                        );
+                                                    }
+                                                });
+                                        });
+                                        ui.add(
+                                            egui::DragValue::new(&mut self.tmp_subscription.months)
+                                                .speed(1.0)
+                                                .max_decimals(0)
+                                                .clamp_range(1..=12)
+                                                .prefix(t!("window.common.every", lang))
+                                                .suffix(t!("window.common.months", lang)),
+                                        );
+                                    }
                                     SimpleRecurrence::Year => {
                                         ui.horizontal(|ui| {
                                             ui.add(
@@ -121,16 +281,125 @@ impl NewIncomeWindow {
                                             egui::DragValue::new(&mut self.tmp_subscription.years)
                                                 .speed(1.0)
                                                 .max_decimals(0)
-                                                .clamp_range(2023..=2100),
+                                                .clamp_range(1..=100)
+                                                .prefix(t!("window.common.every", lang))
+                                                .suffix(t!("window.common.years", lang)),
                                         );
                                     }
                                 };
+
+                                if matches!(
+                                    self.tmp_subscription.recurrence,
+                                    SimpleRecurrence::Month | SimpleRecurrence::Year
+                                ) && self.tmp_subscription.days > 28
+                                {
+                                    ui.small(t!("window.common.month_end_hint", lang));
+                                }
+                            });
+                        });
+
+                        ui.vertical(|ui| {
+                            ui.label(t!("window.common.business_day", lang));
+
+                            egui::ComboBox::from_id_source("Business day rule")
+                                .selected_text(
+                                    self.tmp_subscription.business_day_rule.to_lang_str(lang),
+                                )
+                                .show_ui(ui, |ui| {
+                                    for rule in [
+                                        BusinessDayRule::None,
+                                        BusinessDayRule::Following,
+                                        BusinessDayRule::Preceding,
+                                        BusinessDayRule::ModifiedFollowing,
+                                    ] {
+                                        ui.selectable_value(
+                                            &mut self.tmp_subscription.business_day_rule,
+                                            rule,
+                                            rule.to_lang_str(lang),
+                                        );
+                                    }
+                                });
+                        });
+
+                        ui.vertical(|ui| {
+                            ui.horizontal(|ui| {
+                                ui.checkbox(
+                                    &mut self.tmp_subscription.has_start,
+                                    t!("window.common.starts_on", lang),
+                                );
+                                ui.add_enabled(
+                                    self.tmp_subscription.has_start,
+                                    egui_extras::DatePickerButton::new(
+                                        &mut self.tmp_subscription.start,
+                                    )
+                                    .id_source("Start"),
+                                );
+                            });
+                            ui.horizontal(|ui| {
+                                ui.checkbox(
+                                    &mut self.tmp_subscription.has_end,
+                                    t!("window.common.ends_on", lang),
+                                );
+                                ui.add_enabled(
+                                    self.tmp_subscription.has_end,
+                                    egui_extras::DatePickerButton::new(
+                                        &mut self.tmp_subscription.end,
+                                    )
+                                    .id_source("End"),
+                                );
+                            });
+                            ui.horizontal(|ui| {
+                                ui.checkbox(
+                                    &mut self.tmp_subscription.has_inflation,
+                                    t!("window.common.own_inflation", lang),
+                                );
+                                ui.add_enabled(
+                                    self.tmp_subscription.has_inflation,
+                                    egui::DragValue::new(&mut self.tmp_subscription.inflation)
+                                        .speed(0.01)
+                                        .max_decimals(2)
+                                        .min_decimals(2)
+                                        .clamp_range(0.0..=100.0)
+                                        .suffix(" %"),
+                                );
                             });
                         });
                     });
                     ui.separator();
 
-                    if ui.button(t!("window.common.add", lang)).clicked() {
+                    labels_editor(
+                        ui,
+                        &self.categories,
+                        &mut self.tmp_subscription.category,
+                        &mut self.tmp_subscription.tags,
+                        lang,
+                    );
+                    ui.horizontal(|ui| {
+                        ui.label(t!("window.common.account", lang));
+
+                        account_picker(
+                            ui,
+                            "Account",
+                            &self.accounts,
+                            &mut self.tmp_subscription.account,
+                            lang,
+                        );
+                    });
+                    ui.separator();
+
+                    let valid = self.tmp_subscription.is_period_valid();
+
+                    if !valid {
+                        ui.colored_label(
+                            egui::Color32::RED,
+                            t!("window.common.invalid_period", lang),
+                        );
+                    }
+
+                    if ui
+                        .add_enabled(valid, egui::Button::new(t!("window.common.add", lang)))
+                        .clicked()
+                    {
                         let sub: Subscription = self.tmp_subscription.clone().into();
                         subs = Some(sub);
                     }
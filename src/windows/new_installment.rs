@@ -0,0 +1,123 @@
+use eframe::egui;
+use internationalization::t;
+
+use crate::{
+    windows::{account_picker, labels_editor},
+    Account, Installment, TmpInstallment,
+};
+
+#[derive(Default, Clone)]
+pub struct NewInstallmentWindow {
+    tmp_installment: TmpInstallment,
+    categories: Vec<String>,
+    accounts: Vec<Account>,
+}
+
+impl NewInstallmentWindow {
+    /// Creates the window.
+    /// # Arguments
+    /// - `categories`: The categories the user can choose from.
+    /// - `accounts`: The accounts the user can choose from, besides the main account.
+    pub fn new(categories: &[String], accounts: &[Account]) -> Self {
+        Self {
+            tmp_installment: TmpInstallment::default(),
+            categories: categories.to_vec(),
+            accounts: accounts.to_vec(),
+        }
+    }
+
+    pub fn show(
+        &mut self,
+        ctx: &egui::Context,
+        show: &mut bool,
+        lang: &str,
+    ) -> Option<Installment> {
+        let mut installment: Option<Installment> = None;
+        egui::Window::new(t!("window.installment.title", lang))
+            .open(show)
+            .auto_sized()
+            .default_size([600.0, 200.0])
+            .show(ctx, |ui| {
+                ui.vertical_centered(|ui| {
+                    ui.horizontal_centered(|ui| {
+                        ui.vertical(|ui| {
+                            ui.label(t!("window.common.concept", lang));
+
+                            ui.text_edit_singleline(&mut self.tmp_installment.name);
+                        });
+
+                        ui.vertical(|ui| {
+                            ui.label(t!("window.installment.total", lang));
+
+                            ui.add(
+                                egui::DragValue::new(&mut self.tmp_installment.total)
+                                    .speed(0.01)
+                                    .max_decimals(2)
+                                    .min_decimals(2)
+                                    .suffix(" €"),
+                            );
+                        });
+
+                        ui.vertical(|ui| {
+                            ui.label(t!("window.installment.payments", lang));
+
+                            ui.add(
+                                egui::DragValue::new(&mut self.tmp_installment.payments)
+                                    .speed(1.0)
+                                    .max_decimals(0)
+                                    .clamp_range(1..=360),
+                            );
+                        });
+
+                        ui.vertical(|ui| {
+                            ui.label(t!("window.installment.first", lang));
+
+                            ui.add(egui_extras::DatePickerButton::new(
+                                &mut self.tmp_installment.first,
+                            ));
+                        });
+
+                        ui.vertical(|ui| {
+                            ui.label(t!("window.common.recurrence", lang));
+
+                            ui.add(
+                                egui::DragValue::new(&mut self.tmp_installment.months)
+                                    .speed(1.0)
+                                    .max_decimals(0)
+                                    .clamp_range(1..=12)
+                                    .prefix(t!("window.common.every", lang))
+                                    .suffix(t!("window.common.months", lang)),
+                            );
+                        });
+                    });
+                    ui.separator();
+
+                    labels_editor(
+                        ui,
+                        &self.categories,
+                        &mut self.tmp_installment.category,
+                        &mut self.tmp_installment.tags,
+                        lang,
+                    );
+                    ui.horizontal(|ui| {
+                        ui.label(t!("window.common.account", lang));
+
+                        account_picker(
+                            ui,
+                            "Account",
+                            &self.accounts,
+                            &mut self.tmp_installment.account,
+                            lang,
+                        );
+                    });
+                    ui.separator();
+
+                    if ui.button(t!("window.common.add", lang)).clicked() {
+                        installment = Some(self.tmp_installment.clone().into());
+                    }
+                });
+            });
+
+        installment
+    }
+}
@@ -0,0 +1,118 @@
+use eframe::egui;
+use internationalization::t;
+
+use crate::{
+    windows::{account_picker, labels_editor},
+    Account, Loan, TmpLoan,
+};
+
+#[derive(Default, Clone)]
+pub struct NewLoanWindow {
+    tmp_loan: TmpLoan,
+    categories: Vec<String>,
+    accounts: Vec<Account>,
+}
+
+impl NewLoanWindow {
+    /// Creates the window.
+    /// # Arguments
+    /// - `categories`: The categories the user can choose from.
+    /// - `accounts`: The accounts the user can choose from, besides the main account.
+    pub fn new(categories: &[String], accounts: &[Account]) -> Self {
+        Self {
+            tmp_loan: TmpLoan::default(),
+            categories: categories.to_vec(),
+            accounts: accounts.to_vec(),
+        }
+    }
+
+    pub fn show(&mut self, ctx: &egui::Context, show: &mut bool, lang: &str) -> Option<Loan> {
+        let mut loan: Option<Loan> = None;
+        egui::Window::new(t!("window.loan.title", lang))
+            .open(show)
+            .auto_sized()
+            .default_size([600.0, 200.0])
+            .show(ctx, |ui| {
+                ui.vertical_centered(|ui| {
+                    ui.horizontal_centered(|ui| {
+                        ui.vertical(|ui| {
+                            ui.label(t!("window.common.concept", lang));
+
+                            ui.text_edit_singleline(&mut self.tmp_loan.name);
+                        });
+
+                        ui.vertical(|ui| {
+                            ui.label(t!("window.loan.principal", lang));
+
+                            ui.add(
+                                egui::DragValue::new(&mut self.tmp_loan.principal)
+                                    .speed(0.01)
+                                    .max_decimals(2)
+                                    .min_decimals(2)
+                                    .clamp_range(0.0..=f64::MAX)
+                                    .suffix(" €"),
+                            );
+                        });
+
+                        ui.vertical(|ui| {
+                            ui.label(t!("window.loan.rate", lang));
+
+                            ui.add(
+                                egui::DragValue::new(&mut self.tmp_loan.rate)
+                                    .speed(0.01)
+                                    .max_decimals(2)
+                                    .min_decimals(2)
+                                    .clamp_range(0.0..=100.0)
+                                    .suffix(" %"),
+                            );
+                        });
+
+                        ui.vertical(|ui| {
+                            ui.label(t!("window.loan.term", lang));
+
+                            ui.add(
+                                egui::DragValue::new(&mut self.tmp_loan.term)
+                                    .speed(1.0)
+                                    .max_decimals(0)
+                                    .clamp_range(1..=600)
+                                    .suffix(t!("window.common.months", lang)),
+                            );
+                        });
+
+                        ui.vertical(|ui| {
+                            ui.label(t!("window.loan.first", lang));
+
+                            ui.add(egui_extras::DatePickerButton::new(&mut self.tmp_loan.first));
+                        });
+                    });
+                    ui.separator();
+
+                    labels_editor(
+                        ui,
+                        &self.categories,
+                        &mut self.tmp_loan.category,
+                        &mut self.tmp_loan.tags,
+                        lang,
+                    );
+                    ui.horizontal(|ui| {
+                        ui.label(t!("window.common.account", lang));
+
+                        account_picker(
+                            ui,
+                            "Account",
+                            &self.accounts,
+                            &mut self.tmp_loan.account,
+                            lang,
+                        );
+                    });
+                    ui.separator();
+
+                    if ui.button(t!("window.common.add", lang)).clicked() {
+                        loan = Some(self.tmp_loan.clone().into());
+                    }
+                });
+            });
+
+        loan
+    }
+}
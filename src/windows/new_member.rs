@@ -0,0 +1,43 @@
+use eframe::egui;
+use internationalization::t;
+
+use crate::Member;
+
+#[derive(Default, Clone)]
+pub struct NewMemberWindow {
+    name: String,
+}
+
+impl NewMemberWindow {
+    pub fn show(&mut self, ctx: &egui::Context, show: &mut bool, lang: &str) -> Option<Member> {
+        let mut member: Option<Member> = None;
+        egui::Window::new(t!("window.member.title", lang))
+            .open(show)
+            .auto_sized()
+            .default_size([300.0, 100.0])
+            .show(ctx, |ui| {
+                ui.vertical_centered(|ui| {
+                    ui.horizontal(|ui| {
+                        ui.label(t!("window.member.name", lang));
+
+                        ui.text_edit_singleline(&mut self.name);
+                    });
+                    ui.separator();
+
+                    let name = self.name.trim().to_string();
+
+                    if ui
+                        .add_enabled(
+                            !name.is_empty(),
+                            egui::Button::new(t!("window.common.add", lang)),
+                        )
+                        .clicked()
+                    {
+                        member = Some(Member::new(name));
+                    }
+                });
+            });
+
+        member
+    }
+}
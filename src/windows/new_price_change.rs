@@ -0,0 +1,70 @@
+use chrono::{Months, NaiveDate, Utc};
+use eframe::egui;
+use internationalization::t;
+
+use crate::{Money, Subscription};
+
+#[derive(Clone)]
+pub struct NewPriceChangeWindow {
+    name: String,
+    cost: f64,
+    date: NaiveDate,
+}
+
+impl NewPriceChangeWindow {
+    /// Creates the window to schedule a price change for the given subscription.
+    /// # Arguments
+    /// - `subscription`: The subscription whose price changes.
+    pub fn new(subscription: &Subscription) -> Self {
+        let today = Utc::now().naive_utc().date();
+
+        Self {
+            name: subscription.name().to_string(),
+            cost: subscription.gross_on(today).to_f64(),
+            date: today.checked_add_months(Months::new(1)).unwrap_or(today),
+        }
+    }
+
+    pub fn show(
+        &mut self,
+        ctx: &egui::Context,
+        show: &mut bool,
+        lang: &str,
+    ) -> Option<(NaiveDate, Money)> {
+        let mut change: Option<(NaiveDate, Money)> = None;
+        egui::Window::new(t!("window.price_change.title", name: &self.name, lang))
+            .open(show)
+            .auto_sized()
+            .default_size([600.0, 200.0])
+            .show(ctx, |ui| {
+                ui.vertical_centered(|ui| {
+                    ui.horizontal_centered(|ui| {
+                        ui.vertical(|ui| {
+                            ui.label(t!("window.price_change.new_cost", lang));
+
+                            ui.add(
+                                egui::DragValue::new(&mut self.cost)
+                                    .speed(0.01)
+                                    .max_decimals(2)
+                                    .min_decimals(2)
+                                    .suffix(" €"),
+                            );
+                        });
+
+                        ui.vertical(|ui| {
+                            ui.label(t!("window.price_change.from", lang));
+
+                            ui.add(egui_extras::DatePickerButton::new(&mut self.date));
+                        });
+                    });
+                    ui.separator();
+
+                    if ui.button(t!("window.common.add", lang)).clicked() {
+                        change = Some((self.date, Money::from_f64(self.cost)));
+                    }
+                });
+            });
+
+        change
+    }
+}
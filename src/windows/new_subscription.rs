@@ -1,14 +1,34 @@
 use eframe::egui;
 use internationalization::t;
 
-use crate::{SimpleRecurrence, Subscription, TmpSubscription};
+use chrono::Weekday;
+
+use crate::{
+    nth_to_lang_str, weekday_to_lang_str,
+    windows::{account_picker, labels_editor, uncertainty_editor},
+    Account, BusinessDayRule, SimpleRecurrence, Subscription, TmpSubscription,
+};
 
 #[derive(Default, Clone)]
 pub struct NewSubscriptionWindow {
     tmp_subscription: TmpSubscription,
+    categories: Vec<String>,
+    accounts: Vec<Account>,
 }
 
 impl NewSubscriptionWindow {
+    /// Creates the window.
+    /// # Arguments
+    /// - `categories`: The categories the user can choose from.
+    /// - `accounts`: The accounts the user can choose from, besides the main account.
+    pub fn new(categories: &[String], accounts: &[Account]) -> Self {
+        Self {
+            tmp_subscription: TmpSubscription::default(),
+            categories: categories.to_vec(),
+            accounts: accounts.to_vec(),
+        }
+    }
+
     pub fn show(
         &mut self,
         ctx: &egui::Context,
@@ -39,13 +59,20 @@ impl NewSubscriptionWindow {
                                     .min_decimals(2)
                                     .suffix(" €"),
                             );
+
+                            uncertainty_editor(
+                                ui,
+                                &mut self.tmp_subscription.uncertainty,
+                                self.tmp_subscription.cost,
+                                lang,
+                            );
                         });
 
                         ui.vertical(|ui| {
                             ui.label(t!("window.common.recurrence", lang));
 
                             egui::ComboBox::from_label(t!("window.common.pick", lang))
-                                .selected_text(self.tmp_subscription.recurrence.to_lang_str(&lang))
+                                .selected_text(self.tmp_subscription.recurrence.to_lang_str(lang))
                                 .show_ui(ui, |ui| {
                                     ui.style_mut().wrap = Some(false);
                                     ui.set_min_width(60.0);
@@ -54,11 +81,21 @@ impl NewSubscriptionWindow {
                                         SimpleRecurrence::Day,
                                         t!("window.common.daily", lang),
                                     );
+                                    ui.selectable_value(
+                                        &mut self.tmp_subscription.recurrence,
+                                        SimpleRecurrence::Week,
+                                        t!("window.common.weekly", lang),
+                                    );
                                     ui.selectable_value(
                                         &mut self.tmp_subscription.recurrence,
                                         SimpleRecurrence::Month,
                                         t!("window.common.monthly", lang),
                                     );
+                                    ui.selectable_value(
+                                        &mut self.tmp_subscription.recurrence,
+                                        SimpleRecurrence::MonthWeekday,
+                                        t!("window.common.monthly_weekday", lang),
+                                    );
                                     ui.selectable_value(
                                         &mut self.tmp_subscription.recurrence,
                                         SimpleRecurrence::Year,
@@ -78,6 +115,47 @@ impl NewSubscriptionWindow {
                                                 .suffix(t!("window.common.days", lang)),
                                         );
                                     }
+                                    SimpleRecurrence::Week => {
+                                        egui::ComboBox::from_id_source("Weekday")
+                                            .selected_text(weekday_to_lang_str(
+                                                self.tmp_subscription.weekday,
+                                                lang,
+                                            ))
+                                            .show_ui(ui, |ui| {
+                                                for weekday in [
+                                                    Weekday::Mon,
+                                                    Weekday::Tue,
+                                                    Weekday::Wed,
+                                                    Weekday::Thu,
+                                                    Weekday::Fri,
+                                                    Weekday::Sat,
+                                                    Weekday::Sun,
+                                                ] {
+                                                    ui.selectable_value(
+                                                        &mut self.tmp_subscription.weekday,
+                                                        weekday,
+                                                        weekday_to_lang_str(weekday, lang),
+                                                    );
+                                                }
+                                            });
+                                        ui.add(
+                                            egui::DragValue::new(&mut self.tmp_subscription.weeks)
+                                                .speed(1.0)
+                                                .max_decimals(0)
+                                                .clamp_range(1..=52)
+                                                .prefix(t!("window.common.every", lang))
+                                                .suffix(t!("window.common.weeks", lang)),
+                                        );
+                                        ui.horizontal(|ui| {
+                                            ui.label(t!("window.common.starting", lang));
+                                            ui.add(
+                                                egui_extras::DatePickerButton::new(
+                                                    &mut self.tmp_subscription.anchor,
+                                                )
+                                                .id_source("Anchor"),
+                                            );
+                                        });
+                                    }
                                     SimpleRecurrence::Month => {
                                         ui.add(
                                             egui::DragValue::new(&mut self.tmp_subscription.days)
@@ -96,6 +174,54 @@ impl NewSubscriptionWindow {
                                                 .suffix(t!("window.common.months", lang)),
                                         );
                                     }
+                                    SimpleRecurrence::MonthWeekday => {
+                                        ui.horizontal(|ui| {
+                                            egui::ComboBox::from_id_source("Week of the month")
+                                                .selected_text(nth_to_lang_str(
+                                                    self.tmp_subscription.nth,
+                                                    lang,
+                                                ))
+                                                .show_ui(ui, |ui| {
+                                                    for nth in [1, 2, 3, 4, -1] {
+                                                        ui.selectable_value(
+                                                            &mut self.tmp_subscription.nth,
+                                                            nth,
+                                                            nth_to_lang_str(nth, lang),
+                                                        );
+                                                    }
+                                                });
+                                            egui::ComboBox::from_id_source("Weekday")
+                                                .selected_text(weekday_to_lang_str(
+                                                    self.tmp_subscription.weekday,
+                                                    lang,
+                                                ))
+                                                .show_ui(ui, |ui| {
+                                                    for weekday in [
+                                                        Weekday::Mon,
+                                                        Weekday::Tue,
+                                                        Weekday::Wed,
+                                                        Weekday::Thu,
+                                                        Weekday::Fri,
+                                                        Weekday::Sat,
+                                                        Weekday::Sun,
+                                                    ] {
+                                                        ui.selectable_value(
+                                                            &mut self.tmp_subscription.weekday,
+                                                            weekday,
+                                                            weekday_to_lang_str(weekday, lang),
+                                                        );
+                                                    }
+                                                });
+                                        });
+                                        ui.add(
+                                            egui::DragValue::new(&mut self.tmp_subscription.months)
+                                                .speed(1.0)
+                                                .max_decimals(0)
+                                                .clamp_range(1..=12)
+                                                .prefix(t!("window.common.every", lang))
+                                                .suffix(t!("window.common.months", lang)),
+                                        );
+                                    }
                                     SimpleRecurrence::Year => {
                                         ui.horizontal(|ui| {
                                             ui.add(
@@ -121,16 +247,125 @@ impl NewSubscriptionWindow {
                                             egui::DragValue::new(&mut self.tmp_subscription.years)
                                                 .speed(1.0)
                                                 .max_decimals(0)
-                                                .clamp_range(2023..=2100),
+                                                .clamp_range(1..=100)
+                                                .prefix(t!("window.common.every", lang))
+                                                .suffix(t!("window.common.years", lang)),
                                         );
                                     }
                                 };
+
+                                if matches!(
+                                    self.tmp_subscription.recurrence,
+                                    SimpleRecurrence::Month | SimpleRecurrence::Year
+                                ) && self.tmp_subscription.days > 28
+                                {
+                                    ui.small(t!("window.common.month_end_hint", lang));
+                                }
+                            });
+                        });
+
+                        ui.vertical(|ui| {
+                            ui.label(t!("window.common.business_day", lang));
+
+                            egui::ComboBox::from_id_source("Business day rule")
+                                .selected_text(
+                                    self.tmp_subscription.business_day_rule.to_lang_str(lang),
+                                )
+                                .show_ui(ui, |ui| {
+                                    for rule in [
+                                        BusinessDayRule::None,
+                                        BusinessDayRule::Following,
+                                        BusinessDayRule::Preceding,
+                                        BusinessDayRule::ModifiedFollowing,
+                                    ] {
+                                        ui.selectable_value(
+                                            &mut self.tmp_subscription.business_day_rule,
+                                            rule,
+                                            rule.to_lang_str(lang),
+                                        );
+                                    }
+                                });
+                        });
+
+                        ui.vertical(|ui| {
+                            ui.horizontal(|ui| {
+                                ui.checkbox(
+                                    &mut self.tmp_subscription.has_start,
+                                    t!("window.common.starts_on", lang),
+                                );
+                                ui.add_enabled(
+                                    self.tmp_subscription.has_start,
+                                    egui_extras::DatePickerButton::new(
+                                        &mut self.tmp_subscription.start,
+                                    )
+                                    .id_source("Start"),
+                                );
+                            });
+                            ui.horizontal(|ui| {
+                                ui.checkbox(
+                                    &mut self.tmp_subscription.has_end,
+                                    t!("window.common.ends_on", lang),
+                                );
+                                ui.add_enabled(
+                                    self.tmp_subscription.has_end,
+                                    egui_extras::DatePickerButton::new(
+                                        &mut self.tmp_subscription.end,
+                                    )
+                                    .id_source("End"),
+                                );
+                            });
+                            ui.horizontal(|ui| {
+                                ui.checkbox(
+                                    &mut self.tmp_subscription.has_inflation,
+                                    t!("window.common.own_inflation", lang),
+                                );
+                                ui.add_enabled(
+                                    self.tmp_subscription.has_inflation,
+                                    egui::DragValue::new(&mut self.tmp_subscription.inflation)
+                                        .speed(0.01)
+                                        .max_decimals(2)
+                                        .min_decimals(2)
+                                        .clamp_range(0.0..=100.0)
+                                        .suffix(" %"),
+                                );
                             });
                         });
                     });
                     ui.separator();
 
-                    if ui.button(t!("window.common.add", lang)).clicked() {
+                    labels_editor(
+                        ui,
+                        &self.categories,
+                        &mut self.tmp_subscription.category,
+                        &mut self.tmp_subscription.tags,
+                        lang,
+                    );
+                    ui.horizontal(|ui| {
+                        ui.label(t!("window.common.account", lang));
+
+                        account_picker(
+                            ui,
+                            "Account",
+                            &self.accounts,
+                            &mut self.tmp_subscription.account,
+                            lang,
+                        );
+                    });
+                    ui.separator();
+
+                    let valid = self.tmp_subscription.is_period_valid();
+
+                    if !valid {
+                        ui.colored_label(
+                            egui::Color32::RED,
+                            t!("window.common.invalid_period", lang),
+                        );
+                    }
+
+                    if ui
+                        .add_enabled(valid, egui::Button::new(t!("window.common.add", lang)))
+                        .clicked()
+                    {
                         let sub: Subscription = self.tmp_subscription.clone().into();
                         subs = Some(sub);
                     }
@@ -0,0 +1,133 @@
+use eframe::egui;
+use internationalization::t;
+use uuid::Uuid;
+
+use crate::{windows::account_picker, Account, TmpTransaction, Transaction};
+
+#[derive(Default, Clone)]
+pub struct NewTransactionWindow {
+    tmp_transaction: TmpTransaction,
+    accounts: Vec<Account>,
+    entries: Vec<(Uuid, String)>,
+}
+
+impl NewTransactionWindow {
+    /// Creates the window.
+    /// # Arguments
+    /// - `accounts`: The accounts the user can choose from, besides the main account.
+    /// - `entries`: The uuids and the names of the planned entries the transaction can be linked
+    ///   to.
+    pub fn new(accounts: &[Account], entries: &[(Uuid, String)]) -> Self {
+        Self {
+            tmp_transaction: TmpTransaction::default(),
+            accounts: accounts.to_vec(),
+            entries: entries.to_vec(),
+        }
+    }
+
+    pub fn show(
+        &mut self,
+        ctx: &egui::Context,
+        show: &mut bool,
+        lang: &str,
+    ) -> Option<Transaction> {
+        let mut transaction: Option<Transaction> = None;
+        egui::Window::new(t!("window.transaction.title", lang))
+            .open(show)
+            .auto_sized()
+            .default_size([600.0, 200.0])
+            .show(ctx, |ui| {
+                ui.vertical_centered(|ui| {
+                    ui.horizontal_centered(|ui| {
+                        ui.vertical(|ui| {
+                            ui.label(t!("window.transaction.description", lang));
+
+                            ui.text_edit_singleline(&mut self.tmp_transaction.description);
+                        });
+
+                        ui.vertical(|ui| {
+                            ui.label(t!("window.transaction.amount", lang));
+
+                            ui.add(
+                                egui::DragValue::new(&mut self.tmp_transaction.amount)
+                                    .speed(0.01)
+                                    .max_decimals(2)
+                                    .min_decimals(2)
+                                    .clamp_range(0.0..=f64::MAX)
+                                    .suffix(" €"),
+                            );
+
+                            ui.radio_value(
+                                &mut self.tmp_transaction.income,
+                                false,
+                                t!("window.transaction.spent", lang),
+                            );
+                            ui.radio_value(
+                                &mut self.tmp_transaction.income,
+                                true,
+                                t!("window.transaction.received", lang),
+                            );
+                        });
+
+                        ui.vertical(|ui| {
+                            ui.label(t!("window.common.date", lang));
+
+                            ui.add(egui_extras::DatePickerButton::new(
+                                &mut self.tmp_transaction.date,
+                            ));
+                        });
+
+                        ui.vertical(|ui| {
+                            ui.label(t!("window.common.account", lang));
+
+                            account_picker(
+                                ui,
+                                "Account",
+                                &self.accounts,
+                                &mut self.tmp_transaction.account,
+                                lang,
+                            );
+                        });
+
+                        ui.vertical(|ui| {
+                            ui.label(t!("window.transaction.entry", lang));
+
+                            let none = t!("window.transaction.unplanned", lang);
+
+                            egui::ComboBox::from_id_source("Planned entry")
+                                .selected_text(
+                                    self.entries
+                                        .iter()
+                                        .find(|(uuid, _)| Some(*uuid) == self.tmp_transaction.entry)
+                                        .map_or(none.clone(), |(_, name)| name.clone()),
+                                )
+                                .show_ui(ui, |ui| {
+                                    ui.style_mut().wrap = Some(false);
+                                    ui.set_min_width(60.0);
+                                    ui.selectable_value(
+                                        &mut self.tmp_transaction.entry,
+                                        None,
+                                        none,
+                                    );
+
+                                    for (uuid, name) in &self.entries {
+                                        ui.selectable_value(
+                                            &mut self.tmp_transaction.entry,
+                                            Some(*uuid),
+                                            name,
+                                        );
+                                    }
+                                });
+                        });
+                    });
+                    ui.separator();
+
+                    if ui.button(t!("window.common.add", lang)).clicked() {
+                        transaction = Some(self.tmp_transaction.clone().into());
+                    }
+                });
+            });
+
+        transaction
+    }
+}
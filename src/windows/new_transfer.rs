@@ -0,0 +1,107 @@
+use eframe::egui;
+use internationalization::t;
+
+use crate::{windows::account_picker, Account, TmpTransfer, Transfer};
+
+#[derive(Default, Clone)]
+pub struct NewTransferWindow {
+    tmp_transfer: TmpTransfer,
+    accounts: Vec<Account>,
+}
+
+impl NewTransferWindow {
+    /// Creates the window.
+    /// # Arguments
+    /// - `accounts`: The accounts the user can choose from, besides the main account.
+    pub fn new(accounts: &[Account]) -> Self {
+        Self {
+            tmp_transfer: TmpTransfer::default(),
+            accounts: accounts.to_vec(),
+        }
+    }
+
+    pub fn show(&mut self, ctx: &egui::Context, show: &mut bool, lang: &str) -> Option<Transfer> {
+        let mut transfer: Option<Transfer> = None;
+        egui::Window::new(t!("window.transfer.title", lang))
+            .open(show)
+            .auto_sized()
+            .default_size([600.0, 200.0])
+            .show(ctx, |ui| {
+                ui.vertical_centered(|ui| {
+                    ui.horizontal_centered(|ui| {
+                        ui.vertical(|ui| {
+                            ui.label(t!("window.common.concept", lang));
+
+                            ui.text_edit_singleline(&mut self.tmp_transfer.name);
+                        });
+
+                        ui.vertical(|ui| {
+                            ui.label(t!("window.transfer.amount", lang));
+
+                            ui.add(
+                                egui::DragValue::new(&mut self.tmp_transfer.amount)
+                                    .speed(0.01)
+                                    .max_decimals(2)
+                                    .min_decimals(2)
+                                    .suffix(" €"),
+                            );
+                        });
+
+                        ui.vertical(|ui| {
+                            ui.label(t!("window.transfer.from", lang));
+
+                            account_picker(
+                                ui,
+                                "From account",
+                                &self.accounts,
+                                &mut self.tmp_transfer.from,
+                                lang,
+                            );
+                        });
+
+                        ui.vertical(|ui| {
+                            ui.label(t!("window.transfer.to", lang));
+
+                            account_picker(
+                                ui,
+                                "To account",
+                                &self.accounts,
+                                &mut self.tmp_transfer.to,
+                                lang,
+                            );
+                        });
+
+                        ui.vertical(|ui| {
+                            ui.label(t!("window.common.recurrence", lang));
+
+                            ui.add(
+                                egui::DragValue::new(&mut self.tmp_transfer.day)
+                                    .speed(1.0)
+                                    .max_decimals(0)
+                                    .clamp_range(1..=31)
+                                    .prefix(t!("window.common.the", lang))
+                                    .suffix(t!("window.common.each_month", lang)),
+                            );
+                            ui.add(
+                                egui::DragValue::new(&mut self.tmp_transfer.months)
+                                    .speed(1.0)
+                                    .max_decimals(0)
+                                    .clamp_range(1..=12)
+                                    .prefix(t!("window.common.every", lang))
+                                    .suffix(t!("window.common.months", lang)),
+                            );
+                        });
+                    });
+                    ui.separator();
+
+                    if self.tmp_transfer.from == self.tmp_transfer.to {
+                        ui.label(t!("window.transfer.same_account", lang));
+                    } else if ui.button(t!("window.common.add", lang)).clicked() {
+                        transfer = Some(self.tmp_transfer.clone().into());
+                    }
+                });
+            });
+
+        transfer
+    }
+}
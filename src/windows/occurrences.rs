@@ -0,0 +1,190 @@
+use chrono::{Days, NaiveDate, Utc};
+use eframe::egui;
+use egui_extras::{Column, TableBuilder};
+use internationalization::t;
+
+use crate::{HolidayCalendar, Money, OccurrenceOverride, Subscription};
+
+/// How many days before today the list of occurrences starts.
+const DAYS_BEFORE: u64 = 90;
+/// How many days after today the list of occurrences ends.
+const DAYS_AFTER: u64 = 365;
+
+/// An occurrence being edited.
+#[derive(Clone)]
+struct OccurrenceRow {
+    date: NaiveDate,
+    cost: f64,
+    paid: bool,
+    skipped: bool,
+    has_amount: bool,
+    amount: f64,
+    is_moved: bool,
+    moved_to: NaiveDate,
+}
+
+impl OccurrenceRow {
+    fn new(date: NaiveDate, cost: Money, occurrence: OccurrenceOverride) -> Self {
+        Self {
+            date,
+            cost: cost.to_f64(),
+            paid: occurrence.paid,
+            skipped: occurrence.skipped,
+            has_amount: occurrence.amount.is_some(),
+            amount: occurrence.amount.unwrap_or(cost).to_f64(),
+            is_moved: occurrence.moved_to.is_some(),
+            moved_to: occurrence.moved_to.unwrap_or(date),
+        }
+    }
+
+    fn to_override(&self) -> OccurrenceOverride {
+        OccurrenceOverride {
+            paid: self.paid,
+            skipped: self.skipped,
+            amount: Some(Money::from_f64(self.amount)).filter(|_| self.has_amount),
+            moved_to: Some(self.moved_to).filter(|_| self.is_moved),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct OccurrencesWindow {
+    name: String,
+    rows: Vec<OccurrenceRow>,
+}
+
+impl OccurrencesWindow {
+    /// Creates the window to edit the occurrences of the given subscription, from a few months ago
+    /// until a year from now. The occurrences that were already changed are always listed.
+    /// # Arguments
+    /// - `subscription`: The subscription.
+    /// - `holidays`: The bank holidays.
+    pub fn new(subscription: &Subscription, holidays: &HolidayCalendar) -> Self {
+        let today = Utc::now().naive_utc().date();
+        let from = today
+            .checked_sub_days(Days::new(DAYS_BEFORE))
+            .unwrap_or(today);
+        let to = today
+            .checked_add_days(Days::new(DAYS_AFTER))
+            .unwrap_or(today);
+
+        let mut dates: Vec<NaiveDate> = subscription
+            .occurrences(from, to, holidays)
+            .chain(subscription.overrides().keys().copied())
+            .collect();
+        dates.sort();
+        dates.dedup();
+
+        Self {
+            name: subscription.name().to_string(),
+            rows: dates
+                .into_iter()
+                .map(|date| {
+                    OccurrenceRow::new(
+                        date,
+                        subscription.cost_on(date),
+                        subscription.override_on(date),
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    pub fn show(
+        &mut self,
+        ctx: &egui::Context,
+        show: &mut bool,
+        lang: &str,
+    ) -> Option<Vec<(NaiveDate, OccurrenceOverride)>> {
+        let mut overrides: Option<Vec<(NaiveDate, OccurrenceOverride)>> = None;
+        egui::Window::new(t!("window.occurrences.title", name: &self.name, lang))
+            .open(show)
+            .default_size([700.0, 400.0])
+            .show(ctx, |ui| {
+                ui.vertical_centered(|ui| {
+                    egui::ScrollArea::both()
+                        .id_source("Occurrences scroll area")
+                        .max_height(400.0)
+                        .show(ui, |ui| {
+                            TableBuilder::new(ui)
+                                .striped(true)
+                                .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+                                .column(Column::auto().at_least(100.0))
+                                .column(Column::auto().at_least(60.0))
+                                .column(Column::auto().at_least(60.0))
+                                .column(Column::auto().at_least(150.0))
+                                .column(Column::auto().at_least(150.0))
+                                .header(20.0, |mut header| {
+                                    header.col(|ui| {
+                                        ui.strong(t!("app.table.title.date", lang));
+                                    });
+                                    header.col(|ui| {
+                                        ui.strong(t!("window.occurrences.paid", lang));
+                                    });
+                                    header.col(|ui| {
+                                        ui.strong(t!("window.occurrences.skipped", lang));
+                                    });
+                                    header.col(|ui| {
+                                        ui.strong(t!("window.occurrences.amount", lang));
+                                    });
+                                    header.col(|ui| {
+                                        ui.strong(t!("window.occurrences.moved_to", lang));
+                                    });
+                                })
+                                .body(|mut body| {
+                                    for row in self.rows.iter_mut() {
+                                        body.row(25.0, |mut table_row| {
+                                            table_row.col(|ui| {
+                                                ui.label(row.date.to_string());
+                                            });
+                                            table_row.col(|ui| {
+                                                ui.checkbox(&mut row.paid, "");
+                                            });
+                                            table_row.col(|ui| {
+                                                ui.checkbox(&mut row.skipped, "");
+                                            });
+                                            table_row.col(|ui| {
+                                                ui.checkbox(&mut row.has_amount, "");
+
+                                                if row.has_amount {
+                                                    ui.add(
+                                                        egui::DragValue::new(&mut row.amount)
+                                                            .speed(0.01)
+                                                            .max_decimals(2)
+                                                            .min_decimals(2)
+                                                            .suffix(" €"),
+                                                    );
+                                                } else {
+                                                    ui.label(format!("{:.2} €", row.cost));
+                                                }
+                                            });
+                                            table_row.col(|ui| {
+                                                ui.checkbox(&mut row.is_moved, "");
+                                                ui.add_enabled(
+                                                    row.is_moved,
+                                                    egui_extras::DatePickerButton::new(
+                                                        &mut row.moved_to,
+                                                    )
+                                                    .id_source(&row.date.to_string()),
+                                                );
+                                            });
+                                        });
+                                    }
+                                });
+                        });
+                    ui.separator();
+
+                    if ui.button(t!("window.common.save", lang)).clicked() {
+                        overrides = Some(
+                            self.rows
+                                .iter()
+                                .map(|row| (row.date, row.to_override()))
+                                .collect(),
+                        );
+                    }
+                });
+            });
+
+        overrides
+    }
+}
@@ -0,0 +1,165 @@
+use eframe::egui;
+use internationalization::t;
+use uuid::Uuid;
+
+use crate::{Member, Money, ShareRule, Split};
+
+/// The share of a member while it's being edited.
+#[derive(Clone)]
+struct TmpShare {
+    member: Member,
+    shares: bool,
+    fixed: bool,
+    /// The percentage, or the fixed amount in euros.
+    value: f64,
+}
+
+#[derive(Clone)]
+pub struct SplitWindow {
+    name: String,
+    paid_by: Option<Uuid>,
+    shares: Vec<TmpShare>,
+}
+
+impl SplitWindow {
+    /// Creates the window to split an entry with the household members.
+    /// # Arguments
+    /// - `name`: The name of the entry.
+    /// - `split`: The current split of the entry.
+    /// - `members`: The household members.
+    pub fn new(name: &str, split: &Split, members: &[Member]) -> Self {
+        let shares = members
+            .iter()
+            .map(|member| {
+                let rule = split
+                    .shares
+                    .iter()
+                    .find(|(uuid, _)| *uuid == member.uuid())
+                    .map(|(_, rule)| *rule);
+
+                TmpShare {
+                    member: member.clone(),
+                    shares: rule.is_some(),
+                    fixed: matches!(rule, Some(ShareRule::Fixed(_))),
+                    value: match rule {
+                        Some(ShareRule::Percentage(rate)) => rate as f64 / 100.0,
+                        Some(ShareRule::Fixed(amount)) => amount.to_f64(),
+                        None => 50.0,
+                    },
+                }
+            })
+            .collect();
+
+        Self {
+            name: name.to_string(),
+            paid_by: split.paid_by,
+            shares,
+        }
+    }
+
+    pub fn show(&mut self, ctx: &egui::Context, show: &mut bool, lang: &str) -> Option<Split> {
+        let mut split: Option<Split> = None;
+        egui::Window::new(t!("window.split.title", name: &self.name, lang))
+            .open(show)
+            .auto_sized()
+            .default_size([400.0, 200.0])
+            .show(ctx, |ui| {
+                ui.vertical_centered(|ui| {
+                    ui.horizontal(|ui| {
+                        ui.label(t!("window.split.paid_by", lang));
+
+                        let me = t!("window.split.me", lang);
+                        let selected = self
+                            .shares
+                            .iter()
+                            .find(|s| Some(s.member.uuid()) == self.paid_by)
+                            .map_or(me.clone(), |s| s.member.name().to_string());
+
+                        egui::ComboBox::from_id_source("Paid by")
+                            .selected_text(selected)
+                            .show_ui(ui, |ui| {
+                                ui.selectable_value(&mut self.paid_by, None, me);
+
+                                for share in &self.shares {
+                                    ui.selectable_value(
+                                        &mut self.paid_by,
+                                        Some(share.member.uuid()),
+                                        share.member.name(),
+                                    );
+                                }
+                            });
+                    });
+                    ui.separator();
+
+                    for share in self.shares.iter_mut() {
+                        ui.horizontal(|ui| {
+                            ui.checkbox(&mut share.shares, share.member.name());
+
+                            ui.add_enabled_ui(share.shares, |ui| {
+                                egui::ComboBox::from_id_source(share.member.uuid())
+                                    .selected_text(if share.fixed {
+                                        t!("window.split.fixed", lang)
+                                    } else {
+                                        t!("window.split.percentage", lang)
+                                    })
+                                    .show_ui(ui, |ui| {
+                                        ui.selectable_value(
+                                            &mut share.fixed,
+                                            false,
+                                            t!("window.split.percentage", lang),
+                                        );
+                                        ui.selectable_value(
+                                            &mut share.fixed,
+                                            true,
+                                            t!("window.split.fixed", lang),
+                                        );
+                                    });
+
+                                if share.fixed {
+                                    ui.add(
+                                        egui::DragValue::new(&mut share.value)
+                                            .speed(0.01)
+                                            .max_decimals(2)
+                                            .min_decimals(2)
+                                            .clamp_range(0.0..=f64::MAX)
+                                            .suffix(" €"),
+                                    );
+                                } else {
+                                    ui.add(
+                                        egui::DragValue::new(&mut share.value)
+                                            .speed(0.1)
+                                            .max_decimals(2)
+                                            .clamp_range(0.0..=100.0)
+                                            .suffix(" %"),
+                                    );
+                                }
+                            });
+                        });
+                    }
+                    ui.separator();
+
+                    if ui.button(t!("window.common.save", lang)).clicked() {
+                        split = Some(Split {
+                            paid_by: self.paid_by,
+                            shares: self
+                                .shares
+                                .iter()
+                                .filter(|s| s.shares)
+                                .map(|s| {
+                                    let rule = if s.fixed {
+                                        ShareRule::Fixed(Money::from_f64(s.value))
+                                    } else {
+                                        ShareRule::Percentage((s.value * 100.0).round() as u32)
+                                    };
+
+                                    (s.member.uuid(), rule)
+                                })
+                                .collect(),
+                        });
+                    }
+                });
+            });
+
+        split
+    }
+}
@@ -0,0 +1,103 @@
+use chrono::Utc;
+use eframe::egui;
+use internationalization::t;
+
+use crate::{HolidayCalendar, Subscription, SubscriptionDetails};
+
+#[derive(Clone)]
+pub struct SubscriptionDetailsWindow {
+    subscription: Subscription,
+    holidays: HolidayCalendar,
+    details: SubscriptionDetails,
+}
+
+impl SubscriptionDetailsWindow {
+    /// Creates the window to see and edit the details of the given subscription.
+    /// # Arguments
+    /// - `subscription`: The subscription.
+    /// - `holidays`: The bank holidays.
+    pub fn new(subscription: &Subscription, holidays: &HolidayCalendar) -> Self {
+        Self {
+            subscription: subscription.clone(),
+            holidays: holidays.clone(),
+            details: subscription.details().clone(),
+        }
+    }
+
+    pub fn show(
+        &mut self,
+        ctx: &egui::Context,
+        show: &mut bool,
+        lang: &str,
+    ) -> Option<SubscriptionDetails> {
+        let mut details: Option<SubscriptionDetails> = None;
+        egui::Window::new(t!(
+            "window.details.title",
+            name: self.subscription.name(),
+            lang
+        ))
+        .open(show)
+        .auto_sized()
+        .default_size([400.0, 300.0])
+        .show(ctx, |ui| {
+            ui.vertical_centered(|ui| {
+                egui::Grid::new("Subscription details")
+                    .num_columns(2)
+                    .spacing([10.0, 5.0])
+                    .show(ui, |ui| {
+                        ui.label(t!("window.details.email", lang));
+                        ui.text_edit_singleline(&mut self.details.email);
+                        ui.end_row();
+
+                        ui.label(t!("window.details.cancel_url", lang));
+                        ui.horizontal(|ui| {
+                            ui.text_edit_singleline(&mut self.details.cancel_url);
+
+                            if !self.details.cancel_url.trim().is_empty() {
+                                ui.hyperlink_to(
+                                    t!("window.details.open", lang),
+                                    self.details.cancel_url.trim(),
+                                );
+                            }
+                        });
+                        ui.end_row();
+
+                        ui.label(t!("window.details.notice", lang));
+                        ui.add(
+                            egui::DragValue::new(&mut self.details.notice_days)
+                                .speed(1.0)
+                                .clamp_range(0..=365)
+                                .suffix(t!("window.common.days", lang)),
+                        );
+                        ui.end_row();
+
+                        ui.label(t!("window.details.notes", lang));
+                        ui.text_edit_multiline(&mut self.details.notes);
+                        ui.end_row();
+                    });
+                ui.separator();
+
+                // The deadline changes with the notice period being edited
+                let mut subscription = self.subscription.clone();
+                subscription.set_details(self.details.clone());
+
+                match subscription.cancel_by(Utc::now().naive_utc().date(), &self.holidays) {
+                    Some((deadline, payment)) => ui.label(t!(
+                        "window.details.cancel_by",
+                        deadline: &deadline.to_string(),
+                        payment: &payment.to_string(),
+                        lang
+                    )),
+                    None => ui.label(t!("window.details.no_payments", lang)),
+                };
+                ui.separator();
+
+                if ui.button(t!("window.common.save", lang)).clicked() {
+                    details = Some(self.details.clone());
+                }
+            });
+        });
+
+        details
+    }
+}
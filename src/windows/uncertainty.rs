@@ -0,0 +1,64 @@
+use eframe::egui;
+use internationalization::t;
+
+use crate::{Money, Uncertainty};
+
+/// Draws the fields to make an amount uncertain: a checkbox, and when it's checked the minimum and
+/// the maximum amounts and the probability of the entry happening.
+/// # Arguments
+/// - `ui`: The [`egui::Ui`] to draw the fields into.
+/// - `uncertainty`: The uncertainty to edit, or `None` if the amount is certain.
+/// - `cost`: The expected amount, which the range starts at when the checkbox is checked.
+/// - `lang`: The language.
+pub(crate) fn uncertainty_editor(
+    ui: &mut egui::Ui,
+    uncertainty: &mut Option<Uncertainty>,
+    cost: f64,
+    lang: &str,
+) {
+    let mut variable = uncertainty.is_some();
+
+    if ui
+        .checkbox(&mut variable, t!("window.common.variable_amount", lang))
+        .changed()
+    {
+        *uncertainty = Some(Uncertainty::new(
+            Money::from_f64(cost),
+            Money::from_f64(cost),
+            100,
+        ))
+        .filter(|_| variable);
+    }
+
+    if let Some(uncertainty) = uncertainty {
+        ui.horizontal(|ui| {
+            for (bound, prefix) in [
+                (&mut uncertainty.min, t!("window.common.min", lang)),
+                (&mut uncertainty.max, t!("window.common.max", lang)),
+            ] {
+                ui.add(
+                    egui::DragValue::from_get_set(|value| {
+                        if let Some(value) = value {
+                            *bound = Money::from_f64(value);
+                        }
+
+                        bound.to_f64()
+                    })
+                    .speed(0.01)
+                    .max_decimals(2)
+                    .min_decimals(2)
+                    .prefix(prefix)
+                    .suffix(" €"),
+                );
+            }
+        });
+
+        ui.add(
+            egui::DragValue::new(&mut uncertainty.probability)
+                .speed(1.0)
+                .clamp_range(0..=100)
+                .prefix(t!("window.common.probability", lang))
+                .suffix(" %"),
+        );
+    }
+}